@@ -0,0 +1,55 @@
+//! Compares the cost of `AssetManager::get` with cloning a stored handle weakly or through an `Arc`,
+//! over a million lookups of one key behind an `RwLock<HashMap>`.
+//!
+//! Run with `cargo bench --bench get`.
+
+use bevy::{
+    asset::{Asset, AssetPlugin},
+    prelude::{App, AssetApp, AssetServer, Handle, MinimalPlugins},
+    reflect::TypePath,
+    utils::{hashbrown::HashMap, Instant},
+};
+use bevy_asset_manager::AssetManager;
+use std::{
+    hint::black_box,
+    sync::{Arc, RwLock},
+};
+
+const LOOKUPS: u32 = 1_000_000;
+
+#[derive(Asset, TypePath)]
+struct Sound;
+
+/// Runs `f` `LOOKUPS` times, printing how long it took.
+fn bench(name: &str, mut f: impl FnMut()) {
+    let start = Instant::now();
+    (0..LOOKUPS).for_each(|_| f());
+
+    println!("{name:<24} {:?}", start.elapsed());
+}
+
+fn main() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+        .init_asset::<Sound>();
+    let asset_server = app.world.resource::<AssetServer>().clone();
+    let handle: Handle<Sound> = asset_server.load("sound.ogg");
+
+    let handles: RwLock<HashMap<u32, Handle<Sound>>> =
+        RwLock::new([(0, handle.clone())].into_iter().collect());
+    bench("clone_weak", || {
+        black_box(handles.read().unwrap()[&black_box(0)].clone_weak());
+    });
+
+    let arcs: RwLock<HashMap<u32, Arc<Handle<Sound>>>> =
+        RwLock::new([(0, Arc::new(handle))].into_iter().collect());
+    bench("Arc<Handle> clone", || {
+        black_box(arcs.read().unwrap()[&black_box(0)].clone());
+    });
+
+    let asset_manager = AssetManager::<u32, Sound>::new(asset_server);
+    asset_manager.insert_loaded(0, "sound.ogg");
+    bench("AssetManager::get", || {
+        black_box(asset_manager.get(black_box(0)));
+    });
+}
@@ -0,0 +1,222 @@
+//! Derive macros for `bevy_asset_manager`, re-exported by it behind the `derive` feature.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, quote_spanned};
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, Ident, LitStr};
+
+/// Derives `AssetKey`, `AssetPath` and `AssetKeyCount` for a fieldless enum whose variants are annotated with their asset paths.
+///
+/// Every variant needs an `#[asset(path = "...")]` attribute, and may set its load style with `style = Lazy`, `style = Loaded`
+/// or `style = Preload`. Variants are lazy by default. The enum needs at least one variant, and variants sharing a path
+/// compile with a warning, since keys may deliberately alias one file.
+#[proc_macro_derive(AssetKey, attributes(asset))]
+pub fn derive_asset_key(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Enum(data) = &input.data else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "`AssetKey` can only be derived for enums",
+        ));
+    };
+
+    if data.variants.is_empty() {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "`AssetKey` can't be derived for enums without variants",
+        ));
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let mut variants = Vec::with_capacity(data.variants.len());
+    let mut paths = Vec::with_capacity(data.variants.len());
+    let mut styles = Vec::with_capacity(data.variants.len());
+    let mut warnings = Vec::new();
+
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new_spanned(
+                variant,
+                "`AssetKey` variants can't have fields",
+            ));
+        }
+
+        let mut path = None;
+        let mut style = Ident::new("Lazy", variant.ident.span());
+        for attr in variant
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("asset"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("path") {
+                    path = Some(meta.value()?.parse::<LitStr>()?);
+                    Ok(())
+                } else if meta.path.is_ident("style") {
+                    style = meta.value()?.parse()?;
+                    match style.to_string().as_str() {
+                        "Lazy" | "Loaded" | "Preload" => Ok(()),
+                        _ => Err(Error::new_spanned(
+                            &style,
+                            "expected `Lazy`, `Loaded` or `Preload`",
+                        )),
+                    }
+                } else {
+                    Err(meta.error("expected `path` or `style`"))
+                }
+            })?;
+        }
+
+        let Some(path) = path else {
+            return Err(Error::new_spanned(
+                variant,
+                "missing `#[asset(path = \"...\")]` attribute",
+            ));
+        };
+
+        if variants.contains(&&variant.ident) {
+            return Err(Error::new_spanned(
+                &variant.ident,
+                format!("duplicate asset key `{}`", variant.ident),
+            ));
+        }
+
+        // Keys may alias one file, so a shared path only warns, through a deprecated constant used at the path
+        if let Some(other) = paths
+            .iter()
+            .position(|other: &LitStr| other.value() == path.value())
+        {
+            let note = format!(
+                "asset path `{}` is also used by `{}`",
+                path.value(),
+                variants[other]
+            );
+            warnings.push(quote_spanned! {path.span()=>
+                const _: () = {
+                    #[deprecated(note = #note)]
+                    const DUPLICATE_ASSET_PATH: () = ();
+                    DUPLICATE_ASSET_PATH
+                };
+            });
+        }
+
+        variants.push(&variant.ident);
+        paths.push(path);
+        styles.push(style);
+    }
+
+    let count = variants.len();
+
+    Ok(quote! {
+        #(#warnings)*
+
+        impl #impl_generics ::bevy_asset_manager::AssetPath for #name #ty_generics #where_clause {
+            fn asset_path(&self) -> ::std::string::String {
+                match self {
+                    #(Self::#variants => ::std::string::String::from(#paths),)*
+                }
+            }
+        }
+
+        impl #impl_generics ::bevy_asset_manager::AssetKeyCount for #name #ty_generics #where_clause {
+            const COUNT: usize = #count;
+        }
+
+        impl #impl_generics ::bevy_asset_manager::AssetKey for #name #ty_generics #where_clause {
+            fn keys() -> ::std::vec::Vec<Self> {
+                ::std::vec![#(Self::#variants),*]
+            }
+
+            fn load_style(&self) -> ::bevy_asset_manager::LoadStyle {
+                match self {
+                    #(Self::#variants => ::bevy_asset_manager::LoadStyle::#styles,)*
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::expand;
+    use syn::parse_quote;
+
+    #[test]
+    fn duplicate_paths_are_accepted_with_a_warning() {
+        let expanded = expand(parse_quote! {
+            enum Key {
+                #[asset(path = "a.txt")]
+                A,
+                #[asset(path = "a.txt", style = Loaded)]
+                B,
+            }
+        })
+        .unwrap();
+
+        assert!(expanded.to_string().contains("deprecated"));
+    }
+
+    #[test]
+    fn duplicate_keys_are_rejected() {
+        let error = expand(parse_quote! {
+            enum Key {
+                #[asset(path = "a.txt")]
+                A,
+                #[asset(path = "b.txt")]
+                A,
+            }
+        })
+        .unwrap_err();
+
+        assert_eq!(error.to_string(), "duplicate asset key `A`");
+    }
+
+    #[test]
+    fn distinct_paths_compile_without_warnings() {
+        let expanded = expand(parse_quote! {
+            enum Key {
+                #[asset(path = "a.txt")]
+                A,
+                #[asset(path = "b.txt")]
+                B,
+            }
+        })
+        .unwrap();
+
+        assert!(!expanded.to_string().contains("deprecated"));
+    }
+
+    #[test]
+    fn enums_without_variants_are_rejected() {
+        let error = expand(parse_quote! {
+            enum Key {}
+        })
+        .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "`AssetKey` can't be derived for enums without variants"
+        );
+    }
+
+    #[test]
+    fn structs_are_rejected() {
+        let error = expand(parse_quote! {
+            struct Key;
+        })
+        .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "`AssetKey` can only be derived for enums"
+        );
+    }
+}
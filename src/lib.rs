@@ -7,16 +7,23 @@
 //!
 //! # Example
 //!
-//! ```edition2021
-//! use bevy::prelude::{Commands, Component, Res, Resource, States};
-//! use bevy_asset_manager::{mixed_asset_manager, AssetManager};
-//! use bevy_kira_audio::{AudioApp, AudioChannel, AudioControl, AudioSource};
+//! ```no_run
+//! use bevy::{
+//!     asset::{Asset, AssetPlugin},
+//!     prelude::*,
+//!     reflect::TypePath,
+//! };
+//! use bevy_asset_manager::{mixed_asset_manager, AssetManager, LoadStyle};
+//!
+//! // Stands in for an audio crate's asset, such as `bevy_kira_audio::AudioSource`
+//! #[derive(Asset, TypePath)]
+//! pub struct AudioSource;
 //!
 //! pub struct ShipPlugin;
 //!
 //! impl Plugin for ShipPlugin {
-//!     fn build(&self, app: &mut bevy::prelude::App) {
-//!         app.add_audio_channel::<Ship>()
+//!     fn build(&self, app: &mut App) {
+//!         app.init_asset::<AudioSource>()
 //!             .add_state::<ShipState>()
 //!             .add_systems(Startup, setup)
 //!             .add_systems(Update, handle_input)
@@ -25,9 +32,13 @@
 //!     }
 //! }
 //!
-//! #[derive(Component, Resource)]
+//! #[derive(Component)]
 //! struct Ship;
 //!
+//! // The sound a ship is playing
+//! #[derive(Component)]
+//! struct Playing(Handle<AudioSource>);
+//!
 //! #[derive(States, Clone, Copy, Default, Debug, PartialEq, Eq, Hash)]
 //! enum ShipState {
 //!     #[default]
@@ -39,6 +50,7 @@
 //! type ShipAudioManager = AssetManager<ShipAudio, AudioSource>;
 //!
 //! // Keys for our ship audio
+//! #[derive(Clone, PartialEq, Eq, Hash)]
 //! enum ShipAudio {
 //!     EngineOn,
 //!     EngineOff,
@@ -47,6 +59,7 @@
 //!
 //! // Create an asset manager resource and insert it into our runtime
 //! fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+//!     commands.spawn(Ship);
 //!     commands.insert_resource(
 //!         mixed_asset_manager!(<ShipAudio, AudioSource> binds asset_server.clone(), {
 //!             LoadStyle::Loaded, ShipAudio::EngineOn => "sounds/engine-on.ogg",
@@ -56,21 +69,24 @@
 //!     );
 //! }
 //!
-//! // Retrieve and use our engine on audio asset
-//! fn accelerate(channel: Res<AudioChannel<Ship>>, audio_manager: Res<ShipAudioManager>) {
-//!     audio.stop();
-//!     audio
-//!         .play(audio_manager.get(ShipAudio::EngineOn).unwrap())
-//!         .with_volume(0.5)
-//!         .loop_from(1.0);
+//! // Retrieve and play our engine on audio asset
+//! fn accelerate(
+//!     mut commands: Commands,
+//!     ship: Query<Entity, With<Ship>>,
+//!     audio_manager: Res<ShipAudioManager>,
+//! ) {
+//!     let sound = audio_manager.get(ShipAudio::EngineOn).unwrap();
+//!     commands.entity(ship.single()).insert(Playing(sound));
 //! }
 //!
-//! // Retrieve and use our engine off audio asset
-//! fn idle(channel: Res<AudioChannel<Ship>>, audio_manager: Res<ShipAudioManager>) {
-//!     audio.stop();
-//!     audio
-//!         .play(audio_manager.get(ShipAudio::EngineOff).unwrap())
-//!         .with_volume(0.5);
+//! // Retrieve and play our engine off audio asset
+//! fn idle(
+//!     mut commands: Commands,
+//!     ship: Query<Entity, With<Ship>>,
+//!     audio_manager: Res<ShipAudioManager>,
+//! ) {
+//!     let sound = audio_manager.get(ShipAudio::EngineOff).unwrap();
+//!     commands.entity(ship.single()).insert(Playing(sound));
 //! }
 //!
 //! fn handle_input(keys: Res<Input<KeyCode>>, mut ship_state: ResMut<NextState<ShipState>>) {
@@ -82,6 +98,12 @@
 //!         ship_state.set(ShipState::Idle);
 //!     }
 //! }
+//!
+//! fn main() {
+//!     App::new()
+//!         .add_plugins((MinimalPlugins, AssetPlugin::default(), ShipPlugin))
+//!         .run();
+//! }
 //! ```
 //!
 //! ## Note
@@ -93,31 +115,94 @@
 //! [Bevy Documentation](https://bevyengine.org/).
 
 use bevy::{
-    prelude::{AssetServer, Handle, Resource},
-    utils::hashbrown::HashMap,
+    app::{App, First, Plugin, PostStartup, PreUpdate},
+    asset::{
+        io::AssetSourceId, meta::Settings, LoadState, RecursiveDependencyLoadState, UntypedHandle,
+    },
+    ecs::schedule::{common_conditions::resource_exists, Condition, IntoSystemConfigs},
+    log::error,
+    prelude::{
+        AssetEvent, AssetId, AssetServer, Assets, Commands, Component, Entity, Event, EventReader,
+        EventWriter, FromWorld, Handle, Local, Mut, OnExit, Res, ResMut, Resource, States, World,
+    },
+    utils::{
+        hashbrown::{
+            hash_map::{DefaultHashBuilder, Entry},
+            HashMap, HashSet,
+        },
+        Duration, Instant,
+    },
 };
-use std::{hash::Hash, sync::RwLock};
+use std::{
+    any::Any,
+    error::Error,
+    fmt::{self, Debug, Display, Formatter},
+    hash::Hash,
+    marker::PhantomData,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, Receiver, Sender},
+        Arc, Mutex, RwLock,
+    },
+};
+
+#[cfg(feature = "manifest")]
+mod manifest;
+
+#[cfg(feature = "manifest")]
+pub use manifest::{AssetManifest, ManifestEntry, ManifestError};
+
+#[cfg(feature = "metrics")]
+mod metrics;
+
+#[cfg(feature = "metrics")]
+pub use metrics::{asset_manager_diagnostics, AssetManagerDiagnosticsPlugin, AssetManagerMetrics};
+
+#[cfg(feature = "derive")]
+pub use bevy_asset_manager_derive::AssetKey;
+
+mod untyped;
+
+pub use untyped::UntypedAssetManager;
+
+#[cfg(test)]
+mod tests;
 
 /// Creates an `AssetManager<$key_kind, $asset_kind>` with unloaded assets.
 ///
 /// # Example
 ///
 /// ```rust
-/// use bevy_asset_manager::{AssetManager, lazy_asset_manager};
-/// use bevy_kira_audio::AudioSource;
+/// use bevy::{
+///     asset::{Asset, AssetPlugin},
+///     prelude::{App, AssetApp, AssetServer, MinimalPlugins},
+///     reflect::TypePath,
+/// };
+/// use bevy_asset_manager::lazy_asset_manager;
+///
+/// #[derive(Asset, TypePath)]
+/// struct AudioSource;
 ///
+/// #[derive(Clone, PartialEq, Eq, Hash)]
 /// enum Audio {
 ///    EngineOn,
 ///    EngineOff,
 ///    EngineStall,
 /// }
 ///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+///     .init_asset::<AudioSource>();
+/// let asset_server = app.world.resource::<AssetServer>();
+///
 /// // Create a lazy asset manager with unloaded assets
-/// let lazy_manager = lazy_asset_manager!(<Audio, Texture> binds asset_server.clone(), {
-///     Audio::EnginOn => "sound/engine-on.ogg",
+/// let lazy_manager = lazy_asset_manager!(<Audio, AudioSource> binds asset_server.clone(), {
+///     Audio::EngineOn => "sound/engine-on.ogg",
 ///     Audio::EngineOff => "sound/engine-off.ogg",
 ///     Audio::EngineStall => "sound/engine-stall.ogg",
 /// });
+///
+/// lazy_manager.for_each(|_, asset| assert!(!asset.is_loaded()));
 /// ```
 #[macro_export]
 macro_rules! lazy_asset_manager {
@@ -138,21 +223,36 @@ macro_rules! lazy_asset_manager {
 /// # Example
 ///
 /// ```rust
-/// use bevy_asset_manager::{AssetManager, lazy_asset_manager};
-/// use bevy_kira_audio::AudioSource;
+/// use bevy::{
+///     asset::{Asset, AssetPlugin},
+///     prelude::{App, AssetApp, AssetServer, MinimalPlugins},
+///     reflect::TypePath,
+/// };
+/// use bevy_asset_manager::loaded_asset_manager;
+///
+/// #[derive(Asset, TypePath)]
+/// struct AudioSource;
 ///
-/// enum EngineAudio {
+/// #[derive(Clone, PartialEq, Eq, Hash)]
+/// enum Audio {
 ///    EngineOn,
 ///    EngineOff,
 ///    EngineStall,
 /// }
 ///
-/// // Create a lazy asset manager with unloaded assets
-/// let lazy_manager = loaded_asset_manager!(<Audio, AudioSource> binds asset_server.clone(), {
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+///     .init_asset::<AudioSource>();
+/// let asset_server = app.world.resource::<AssetServer>();
+///
+/// // Create a loaded asset manager with loaded assets
+/// let loaded_manager = loaded_asset_manager!(<Audio, AudioSource> binds asset_server.clone(), {
 ///     Audio::EngineOn => "sound/engine-on.ogg",
 ///     Audio::EngineOff => "sound/engine-off.ogg",
 ///     Audio::EngineStall => "sound/engine-stall.ogg",
 /// });
+///
+/// loaded_manager.for_each(|_, asset| assert!(asset.is_loaded()));
 /// ```
 #[macro_export]
 macro_rules! loaded_asset_manager {
@@ -173,21 +273,36 @@ macro_rules! loaded_asset_manager {
 /// # Example
 ///
 /// ```rust
-/// use bevy_asset_manager::{AssetManager, lazy_asset_manager};
-/// use bevy_kira_audio::AudioSource;
+/// use bevy::{
+///     asset::{Asset, AssetPlugin},
+///     prelude::{App, AssetApp, AssetServer, MinimalPlugins},
+///     reflect::TypePath,
+/// };
+/// use bevy_asset_manager::{mixed_asset_manager, LoadStyle};
 ///
-/// enum EngineAudio {
+/// #[derive(Asset, TypePath)]
+/// struct AudioSource;
+///
+/// #[derive(Clone, PartialEq, Eq, Hash)]
+/// enum Audio {
 ///    EngineOn,
 ///    EngineOff,
 ///    EngineStall,
 /// }
 ///
-/// // Create a lazy asset manager with unloaded assets
-/// let lazy_manager = mixed_asset_manager!(<Audio, AudioSource> binds asset_server.clone(), {
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+///     .init_asset::<AudioSource>();
+/// let asset_server = app.world.resource::<AssetServer>();
+///
+/// // Create a mixed asset manager with loaded and unloaded assets
+/// let mixed_manager = mixed_asset_manager!(<Audio, AudioSource> binds asset_server.clone(), {
 ///     LoadStyle::Loaded, Audio::EngineOn => "sound/engine-on.ogg",
 ///     LoadStyle::Loaded, Audio::EngineOff => "sound/engine-off.ogg",
 ///     LoadStyle::Lazy, Audio::EngineStall => "sound/engine-stall.ogg",
 /// });
+///
+/// mixed_manager.for_each(|key, asset| assert_eq!(asset.is_loaded(), *key != Audio::EngineStall));
 /// ```
 #[macro_export]
 macro_rules! mixed_asset_manager {
@@ -197,41 +312,434 @@ macro_rules! mixed_asset_manager {
 
     (<$key_kind:ty, $asset_kind:ty> binds $asset_server:expr, { $($load_kind:expr, $key:expr => $path:expr),* $(,)? }) => ({
         let asset_manager = $crate::AssetManager::<$key_kind, $asset_kind>::new($asset_server);
-        let mut lazy = vec![];
-        let mut loaded = vec![];
-
-        $(match $load_kind {
-            $crate::LoadStyle::Lazy => lazy.insert(($key, $path)),
-            $crate::LoadStyle::Loaded => loaded.insert(($key, $path)),
-        })*
-
-        asset_manager.insert_many(&lazy);
-        asset_manager.insert_many(&loaded);
+        $(asset_manager.insert_with_style($key, $path, $load_kind);)*
 
         asset_manager
     });
 }
 
+/// Errors returned by fallible `AssetManager` operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssetManagerError {
+    /// No asset is registered under the requested key.
+    MissingKey,
+    /// The asset server failed to load the asset.
+    LoadFailed,
+    /// The asset did not finish loading before the timeout elapsed.
+    Timeout,
+    /// A thread panicked while holding one of the manager's locks.
+    LockPoisoned,
+    /// A subasset label is empty.
+    EmptyLabel,
+}
+
+impl Display for AssetManagerError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            AssetManagerError::MissingKey => write!(f, "no asset is registered under the key"),
+            AssetManagerError::LoadFailed => write!(f, "the asset failed to load"),
+            AssetManagerError::Timeout => write!(f, "timed out waiting for the asset to load"),
+            AssetManagerError::LockPoisoned => write!(f, "the asset manager's lock is poisoned"),
+            AssetManagerError::EmptyLabel => write!(f, "the subasset label is empty"),
+        }
+    }
+}
+
+impl Error for AssetManagerError {}
+
+/// The time each frame `asset_manager_budget_load` may spend promoting lazy assets.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct LoadBudget(pub Duration);
+
+/// Event sent by `asset_manager_events` when a managed asset finishes loading.
+#[derive(Event)]
+pub struct AssetLoaded<Key, Asset>
+where
+    Key: Send + Sync + 'static,
+    Asset: bevy::asset::Asset,
+{
+    /// The key the asset is registered under.
+    pub key: Key,
+    /// The id of the loaded asset.
+    pub id: AssetId<Asset>,
+}
+
+/// Event sent by `asset_manager_events` when a managed asset fails to load.
+#[derive(Event)]
+pub struct AssetFailed<Key, Asset>
+where
+    Key: Send + Sync + 'static,
+    Asset: bevy::asset::Asset,
+{
+    /// The key the asset is registered under.
+    pub key: Key,
+    /// The id of the failed asset.
+    pub id: AssetId<Asset>,
+}
+
+/// Event sent by `asset_manager_events` whenever the load state of a managed asset changes.
+#[derive(Event)]
+pub struct AssetStateChanged<Key, Asset>
+where
+    Key: Send + Sync + 'static,
+    Asset: bevy::asset::Asset,
+{
+    /// The key the asset is registered under.
+    pub key: Key,
+    /// The id of the asset.
+    pub id: AssetId<Asset>,
+    /// The new load state of the asset.
+    pub state: LoadState,
+}
+
+/// Event sent by `asset_manager_hot_reload` when a loaded asset changes on disk while the asset server is watching for changes.
+#[derive(Event)]
+pub struct AssetModified<Key, Asset>
+where
+    Key: Send + Sync + 'static,
+    Asset: bevy::asset::Asset,
+{
+    /// The key the asset is registered under.
+    pub key: Key,
+    /// The id of the modified asset.
+    pub id: AssetId<Asset>,
+}
+
+/// Event sent once by `check_all_loaded` when every requested asset of a manager has finished loading.
+#[derive(Event)]
+pub struct AllAssetsLoaded<Key, Asset>(PhantomData<fn() -> (Key, Asset)>)
+where
+    Key: Send + Sync + 'static,
+    Asset: bevy::asset::Asset;
+
+impl<Key, Asset> Default for AllAssetsLoaded<Key, Asset>
+where
+    Key: Send + Sync + 'static,
+    Asset: bevy::asset::Asset,
+{
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
 /// The load style of an asset used in `mixed_asset_manager!` to determine if an asset should be loaded eagerly or lazily.
-#[derive(Debug)]
+///
+/// # Example
+///
+/// ```rust
+/// use bevy_asset_manager::LoadStyle;
+///
+/// assert_eq!(LoadStyle::Lazy, LoadStyle::Lazy);
+/// assert_ne!(LoadStyle::Lazy, LoadStyle::Loaded);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "manifest", derive(serde::Serialize, serde::Deserialize))]
 pub enum LoadStyle {
     /// Lazily load the asset.
     Lazy,
     /// Eagerly load the asset.
     Loaded,
+    /// Lazily load the asset until the preload phase, when it is eagerly loaded.
+    Preload,
+}
+
+/// The strength of handles returned by an `AssetManager`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Strength {
+    /// Weak handles, which don't keep their assets alive.
+    #[default]
+    Weak,
+    /// Strong handles, which keep their assets alive until dropped.
+    Strong,
+}
+
+/// Component holding strong handles to a group of assets, which are released once its entity is despawned.
+#[derive(Component)]
+pub struct AssetLease<Asset>(pub Vec<Handle<Asset>>)
+where
+    Asset: bevy::asset::Asset;
+
+/// Resource mapping the ids of an `AssetManager`'s loaded assets back to their keys, kept in sync by `asset_manager_key_index`.
+#[derive(Resource)]
+pub struct AssetKeyIndex<Key, Asset>
+where
+    Key: Send + Sync + 'static,
+    Asset: bevy::asset::Asset,
+{
+    keys: HashMap<AssetId<Asset>, Key>,
+}
+
+impl<Key, Asset> AssetKeyIndex<Key, Asset>
+where
+    Key: Send + Sync + 'static,
+    Asset: bevy::asset::Asset,
+{
+    /// Gets the key a loaded asset is registered under.
+    pub fn get(&self, id: AssetId<Asset>) -> Option<&Key> {
+        self.keys.get(&id)
+    }
+}
+
+impl<Key, Asset> Default for AssetKeyIndex<Key, Asset>
+where
+    Key: Send + Sync + 'static,
+    Asset: bevy::asset::Asset,
+{
+    fn default() -> Self {
+        Self {
+            keys: HashMap::new(),
+        }
+    }
+}
+
+/// Resource tracking the load progress of every asset manager registered with an `AssetManagerPlugin`.
+///
+/// Only assets which have been requested are counted, so lazy assets don't hold progress back until they're loaded.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LoadProgress {
+    /// How many requested assets have finished loading.
+    pub loaded: usize,
+    /// How many assets have been requested.
+    pub total: usize,
+}
+
+/// Counts of an `AssetManager`'s assets by load state, as returned by `state_counts`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StateCounts {
+    /// Assets which are still loading.
+    pub loading: usize,
+    /// Assets which have finished loading.
+    pub loaded: usize,
+    /// Assets which failed to load.
+    pub failed: usize,
+    /// Lazy assets, along with loaded assets the asset server is no longer tracking.
+    pub not_started: usize,
 }
 
 /// Enum representing different states of an asset handle.
-enum AssetHandle<Asset>
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::{asset::Asset, prelude::Handle, reflect::TypePath};
+/// use bevy_asset_manager::AssetHandle;
+///
+/// #[derive(Asset, TypePath)]
+/// struct Sound;
+///
+/// let lazy = AssetHandle::<Sound>::Lazy("sound/engine-on.ogg".to_owned());
+/// assert!(!lazy.is_loaded());
+/// assert_eq!(lazy.path(), "sound/engine-on.ogg");
+/// assert!(lazy.handle().is_none());
+///
+/// let loaded = AssetHandle::<Sound>::Loaded {
+///     path: "sound/engine-off.ogg".to_owned(),
+///     handle: Handle::default(),
+/// };
+/// assert!(loaded.is_loaded());
+/// assert_eq!(loaded.path(), "sound/engine-off.ogg");
+/// assert_eq!(loaded.handle(), Some(&Handle::default()));
+/// ```
+pub enum AssetHandle<Asset>
 where
     Asset: bevy::asset::Asset,
 {
     /// Represents a lazy asset handle with the path.
     Lazy(String),
-    /// Represents a loaded asset handle.
-    Loaded(Handle<Asset>),
+    /// Represents a loaded asset handle with the path it was loaded from.
+    Loaded { path: String, handle: Handle<Asset> },
+}
+
+impl<Asset> AssetHandle<Asset>
+where
+    Asset: bevy::asset::Asset,
+{
+    /// Checks whether the asset has been loaded.
+    pub fn is_loaded(&self) -> bool {
+        matches!(self, AssetHandle::Loaded { .. })
+    }
+
+    /// Gets the path of the asset, regardless of whether it has been loaded.
+    pub fn path(&self) -> &str {
+        match self {
+            AssetHandle::Lazy(path) | AssetHandle::Loaded { path, .. } => path,
+        }
+    }
+
+    /// Gets the handle of the asset if it has been loaded.
+    pub fn handle(&self) -> Option<&Handle<Asset>> {
+        match self {
+            AssetHandle::Lazy(_) => None,
+            AssetHandle::Loaded { handle, .. } => Some(handle),
+        }
+    }
+}
+
+impl<Asset> Debug for AssetHandle<Asset>
+where
+    Asset: bevy::asset::Asset,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            AssetHandle::Lazy(path) => f.debug_tuple("Lazy").field(path).finish(),
+            AssetHandle::Loaded { path, handle } => f
+                .debug_struct("Loaded")
+                .field("path", path)
+                .field("handle", handle)
+                .finish(),
+        }
+    }
+}
+
+/// Formats an asset handle as its variant and path only, for `AssetManager`'s `Debug` impl.
+struct EntryPath<'a, Asset>(&'a AssetHandle<Asset>)
+where
+    Asset: bevy::asset::Asset;
+
+impl<Asset> Debug for EntryPath<'_, Asset>
+where
+    Asset: bevy::asset::Asset,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let variant = if self.0.is_loaded() { "Loaded" } else { "Lazy" };
+
+        f.debug_tuple(variant).field(&self.0.path()).finish()
+    }
+}
+
+/// Resource sharing loaded handles by path between every `AssetManager` bound to it.
+///
+/// The asset server already shares one handle between loads of the same path, so the cache's part is normalizing paths,
+/// letting equivalent spellings like `./a.png` and `a.png` share that handle too. Managers inserted with `insert_asset_manager`,
+/// `init_asset_manager` or an `AssetManagerPlugin` are bound to the app's cache automatically.
+///
+/// Cloning the cache shares its contents. Handles held by the cache are weak, so it never keeps assets loaded; an entry whose
+/// asset was unloaded is replaced by the next load of its path.
+#[derive(Resource)]
+pub struct HandleCache<Asset>
+where
+    Asset: bevy::asset::Asset,
+{
+    handles: Arc<RwLock<HashMap<String, Handle<Asset>>>>,
+}
+
+impl<Asset> HandleCache<Asset>
+where
+    Asset: bevy::asset::Asset,
+{
+    /// Creates a new, empty `HandleCache` instance.
+    pub fn new() -> Self {
+        Self {
+            handles: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Gets the number of cached handles, including those whose assets have since been unloaded.
+    pub fn len(&self) -> usize {
+        self.handles.read().unwrap().len()
+    }
+
+    /// Checks whether the cache holds no handles.
+    pub fn is_empty(&self) -> bool {
+        self.handles.read().unwrap().is_empty()
+    }
+
+    /// Removes the handle cached for a path, returning it if it existed.
+    pub fn remove(&self, path: &str) -> Option<Handle<Asset>> {
+        self.handles.write().unwrap().remove(&normalize_path(path))
+    }
+
+    /// Removes every cached handle.
+    pub fn clear(&self) {
+        self.handles.write().unwrap().clear();
+    }
+
+    /// Gets a strong handle to the asset cached for a path if it's still loaded, loading and caching it otherwise.
+    fn get_or_load(&self, asset_server: &AssetServer, path: &str) -> Handle<Asset> {
+        let path = normalize_path(path);
+        let mut handles = self.handles.write().unwrap();
+        if let Some(handle) = handles
+            .get(&path)
+            .and_then(|handle| asset_server.get_id_handle(handle.id()))
+        {
+            return handle;
+        }
+
+        let handle = asset_server.load(path.clone());
+        handles.insert(path, handle.clone_weak());
+
+        handle
+    }
+}
+
+impl<Asset> Clone for HandleCache<Asset>
+where
+    Asset: bevy::asset::Asset,
+{
+    fn clone(&self) -> Self {
+        Self {
+            handles: self.handles.clone(),
+        }
+    }
+}
+
+impl<Asset> Default for HandleCache<Asset>
+where
+    Asset: bevy::asset::Asset,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Composes a path with an asset source, unless it names its own source.
+fn compose_source_path(source: &AssetSourceId, path: &str) -> String {
+    match source {
+        AssetSourceId::Name(source) if !path.contains("://") => format!("{source}://{path}"),
+        _ => path.to_owned(),
+    }
+}
+
+/// Normalizes path separators and leading `./` so equivalent paths share a cache entry.
+fn normalize_path(path: &str) -> String {
+    path.replace('\\', "/").trim_start_matches("./").to_owned()
+}
+
+/// Trait for keys which know the path of their own asset.
+///
+/// Implementing it once on a key enum lets `AssetManager::from_key_paths` register assets without listing paths.
+pub trait AssetPath {
+    /// Gets the path of the key's asset.
+    fn asset_path(&self) -> String;
+}
+
+/// Trait for keys with a known, fixed number of values, such as fieldless enums.
+pub trait AssetKeyCount {
+    /// The number of distinct keys.
+    const COUNT: usize;
+}
+
+/// Trait for keys which know every one of their values, along with the path and load style of each value's asset.
+///
+/// With the `derive` feature enabled, it can be derived for fieldless enums along with `AssetPath` and `AssetKeyCount`,
+/// annotating each variant with `#[asset(path = "...")]` and optionally `style = Loaded` or `style = Preload`.
+pub trait AssetKey: AssetPath + AssetKeyCount + Sized {
+    /// Gets every key.
+    fn keys() -> Vec<Self>;
+
+    /// Gets the load style of the key's asset.
+    fn load_style(&self) -> LoadStyle;
 }
 
+/// Hook called by an `AssetManager` whenever a lazy asset is promoted to a loaded one.
+pub type PromoteHook<Key> = Box<dyn Fn(Key, &str) + Send + Sync>;
+
+/// Clock read by an `AssetManager` to timestamp loads and accesses.
+pub type Clock = Box<dyn Fn() -> Instant + Send + Sync>;
+
+/// An observed key, its last delivered load state and the sender delivering its transitions.
+type KeyObserver<Key> = (Key, Option<LoadState>, Sender<LoadState>);
+
 /// Resource representing the asset manager.
 #[derive(Resource)]
 pub struct AssetManager<Key, Asset>
@@ -240,119 +748,2514 @@ where
     Asset: bevy::asset::Asset,
 {
     assets: RwLock<HashMap<Key, AssetHandle<Asset>>>,
+    untyped: UntypedAssetManager<Key>,
+    sizes: RwLock<HashMap<Key, u64>>,
+    styles: RwLock<HashMap<Key, LoadStyle>>,
+    accessed: Mutex<HashMap<Key, Instant>>,
+    load_times: Mutex<HashMap<Key, (Instant, Option<Duration>)>>,
+    tags: RwLock<HashMap<String, HashSet<Key>>>,
+    scopes: RwLock<HashMap<Key, Box<dyn Any + Send + Sync>>>,
     asset_server: AssetServer,
+    source: RwLock<AssetSourceId<'static>>,
+    handle_cache: RwLock<Option<HandleCache<Asset>>>,
+    strength: RwLock<Strength>,
+    on_promote: RwLock<Option<PromoteHook<Key>>>,
+    clock: RwLock<Clock>,
+    promoted: Mutex<Vec<(Key, String)>>,
+    observers: Mutex<Vec<KeyObserver<Key>>>,
+    paused: AtomicBool,
+    auto_shrink: AtomicBool,
+    strict: Option<fn(&Key)>,
+    fallback: RwLock<Option<Handle<Asset>>>,
+    #[cfg(feature = "metrics")]
+    counters: metrics::Counters,
 }
 
 impl<Key, Asset> AssetManager<Key, Asset>
 where
-    Key: PartialEq + Eq + Hash + Copy,
+    Key: PartialEq + Eq + Hash + Clone,
     Asset: bevy::asset::Asset,
 {
     /// Creates a new `AssetManager` instance.
     pub fn new(asset_server: AssetServer) -> Self {
+        Self::with_capacity(asset_server, 0)
+    }
+
+    /// Creates a new `AssetManager` instance which logs an error whenever `get` is called with an unregistered key.
+    ///
+    /// Non-strict managers silently return `None` instead.
+    pub fn new_strict(asset_server: AssetServer) -> Self
+    where
+        Key: Debug,
+    {
+        Self {
+            strict: Some(|key| error!("no asset is registered under the key {key:?}")),
+            ..Self::new(asset_server)
+        }
+    }
+
+    /// Creates a new `AssetManager` instance with space for at least `capacity` assets.
+    pub fn with_capacity(asset_server: AssetServer, capacity: usize) -> Self {
         Self {
-            assets: RwLock::new(HashMap::new()),
+            assets: RwLock::new(HashMap::with_capacity(capacity)),
+            untyped: UntypedAssetManager::new(asset_server.clone()),
+            sizes: RwLock::new(HashMap::new()),
+            styles: RwLock::new(HashMap::new()),
+            accessed: Mutex::new(HashMap::new()),
+            load_times: Mutex::new(HashMap::new()),
+            tags: RwLock::new(HashMap::new()),
+            scopes: RwLock::new(HashMap::new()),
             asset_server,
+            source: RwLock::new(AssetSourceId::Default),
+            handle_cache: RwLock::new(None),
+            strength: RwLock::new(Strength::Weak),
+            on_promote: RwLock::new(None),
+            clock: RwLock::new(Box::new(Instant::now)),
+            promoted: Mutex::new(Vec::new()),
+            observers: Mutex::new(Vec::new()),
+            paused: AtomicBool::new(false),
+            auto_shrink: AtomicBool::new(false),
+            strict: None,
+            fallback: RwLock::new(None),
+            #[cfg(feature = "metrics")]
+            counters: metrics::Counters::default(),
         }
     }
 
-    /// Inserts a lazy asset into the manager.
-    pub fn insert(&self, key: Key, path: &str) {
-        self.assets
-            .write()
-            .unwrap()
-            .insert(key, AssetHandle::Lazy(path.to_owned()));
+    /// Creates a new `AssetManager` instance with space for every key.
+    pub fn with_capacity_for(asset_server: AssetServer) -> Self
+    where
+        Key: AssetKeyCount,
+    {
+        Self::with_capacity(asset_server, Key::COUNT)
     }
 
-    /// Inserts multiple lazy assets into the manager.
-    pub fn insert_many(&self, pairs: &[(Key, &str)]) {
-        let mut lock = self.assets.write().unwrap();
+    /// Creates a new `AssetManager` instance with every key inserted lazily at its `AssetPath::asset_path`.
+    pub fn from_key_paths<I>(asset_server: AssetServer, keys: I) -> Self
+    where
+        Key: AssetPath,
+        I: IntoIterator<Item = Key>,
+    {
+        let asset_manager = Self::new(asset_server);
+        keys.into_iter()
+            .for_each(|key| asset_manager.insert(key.clone(), &key.asset_path()));
 
-        pairs.iter().for_each(|(key, path)| {
-            lock.insert(*key, AssetHandle::Lazy(path.to_owned().to_owned()));
+        asset_manager
+    }
+
+    /// Creates a new `AssetManager` instance with every key inserted at its `AssetPath::asset_path` with its `AssetKey::load_style`.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "derive", doc = "```rust")]
+    #[cfg_attr(not(feature = "derive"), doc = "```ignore")]
+    /// use bevy::{
+    ///     asset::{Asset, AssetPlugin},
+    ///     prelude::{App, AssetApp, AssetServer, MinimalPlugins},
+    ///     reflect::TypePath,
+    /// };
+    /// use bevy_asset_manager::{AssetKey, AssetManager};
+    ///
+    /// #[derive(Asset, TypePath)]
+    /// struct AudioSource;
+    ///
+    /// #[derive(AssetKey, Clone, PartialEq, Eq, Hash)]
+    /// enum ShipAudio {
+    ///     #[asset(path = "sounds/engine-on.ogg", style = Loaded)]
+    ///     EngineOn,
+    ///     #[asset(path = "sounds/engine-off.ogg", style = Loaded)]
+    ///     EngineOff,
+    ///     #[asset(path = "sounds/warp.ogg")]
+    ///     Warp,
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+    ///     .init_asset::<AudioSource>();
+    /// let asset_server = app.world.resource::<AssetServer>();
+    ///
+    /// let ship_audio = AssetManager::<ShipAudio, AudioSource>::from_keys(asset_server.clone());
+    /// assert_eq!(ship_audio.len(), 3);
+    /// ship_audio.for_each(|key, asset| assert_eq!(asset.is_loaded(), *key != ShipAudio::Warp));
+    /// ```
+    pub fn from_keys(asset_server: AssetServer) -> Self
+    where
+        Key: AssetKey,
+    {
+        let asset_manager = Self::with_capacity(asset_server, Key::COUNT);
+        Key::keys().into_iter().for_each(|key| {
+            let style = key.load_style();
+            let path = key.asset_path();
+            asset_manager.insert_with_style(key, &path, style);
         });
+
+        asset_manager
     }
 
-    /// Inserts a loaded asset into the manager.
-    pub fn insert_loaded(&self, key: Key, path: &str) {
-        self.assets.write().unwrap().insert(
-            key,
-            AssetHandle::Loaded(self.asset_server.load(path.to_owned())),
-        );
+    /// Creates a new `AssetManager` instance with every key and path pair inserted lazily.
+    pub fn from_pairs<I>(asset_server: AssetServer, pairs: I) -> Self
+    where
+        I: IntoIterator<Item = (Key, String)>,
+    {
+        let pairs: Vec<(Key, String)> = pairs.into_iter().collect();
+        let asset_manager = Self::with_capacity(asset_server, pairs.len());
+        asset_manager.insert_many(&borrow_pairs(&pairs));
+
+        asset_manager
     }
 
-    /// Inserts multiple loaded assets into the manager.
-    pub fn insert_many_loaded(&self, pairs: &[(Key, &str)]) {
-        let mut lock = self.assets.write().unwrap();
+    /// Creates a new `AssetManager` instance with every key and path pair inserted and loaded.
+    pub fn from_pairs_loaded<I>(asset_server: AssetServer, pairs: I) -> Self
+    where
+        I: IntoIterator<Item = (Key, String)>,
+    {
+        let pairs: Vec<(Key, String)> = pairs.into_iter().collect();
+        let asset_manager = Self::with_capacity(asset_server, pairs.len());
+        asset_manager.insert_many_loaded(&borrow_pairs(&pairs));
 
-        pairs.iter().for_each(|(key, path)| {
-            lock.insert(
-                *key,
-                AssetHandle::Loaded(self.asset_server.load(path.to_owned().to_owned())),
-            );
-        });
+        asset_manager
     }
 
-    /// Loads an asset if it was added lazily, doing nothing if it is already loaded.
-    pub fn load(&self, key: Key) {
-        if let Some(asset) = self.assets.write().unwrap().get_mut(&key) {
-            match asset {
-                AssetHandle::Lazy(path) => {
-                    *asset = AssetHandle::Loaded(self.asset_server.load(path.to_owned()))
-                }
-                AssetHandle::Loaded(_) => {}
-            }
-        }
+    /// Creates a copy of the manager bound to another asset server, with every asset lazy again.
+    ///
+    /// Asset sizes, styles, tags and untyped assets are copied along with the asset source, handle strength and strictness,
+    /// but not the handle cache.
+    pub fn with_asset_server(&self, asset_server: AssetServer) -> Self {
+        let mut asset_manager = Self::with_capacity(asset_server.clone(), self.len());
+        asset_manager.untyped = self.untyped.with_asset_server(asset_server);
+        asset_manager.strict = self.strict;
+        *asset_manager.source.write().unwrap() = self.source();
+        *asset_manager.strength.write().unwrap() = self.handle_strength();
+
+        *asset_manager.assets.write().unwrap() = self
+            .assets
+            .read()
+            .unwrap()
+            .iter()
+            .map(|(key, asset)| (key.clone(), AssetHandle::Lazy(asset.path().to_owned())))
+            .collect();
+        *asset_manager.sizes.write().unwrap() = self.sizes.read().unwrap().clone();
+        *asset_manager.styles.write().unwrap() = self.styles.read().unwrap().clone();
+        *asset_manager.tags.write().unwrap() = self.tags.read().unwrap().clone();
+
+        asset_manager
     }
 
-    /// Loads multiple assets if they were added lazily, doing nothing if they are already loaded.
-    pub fn load_many(&self, keys: &[Key]) {
-        let mut lock = self.assets.write().unwrap();
+    /// Inserts multiple lazy assets, returning the manager for chaining.
+    pub fn with(self, pairs: &[(Key, &str)]) -> Self {
+        self.insert_many(pairs);
+        self
+    }
 
-        keys.iter().for_each(|key| {
-            if let Some(asset) = lock.get_mut(key) {
-                match asset {
-                    AssetHandle::Lazy(path) => {
-                        *asset = AssetHandle::Loaded(self.asset_server.load(path.to_owned()))
-                    }
-                    AssetHandle::Loaded(_) => {}
-                }
-            }
-        })
+    /// Inserts multiple loaded assets, returning the manager for chaining.
+    pub fn with_loaded(self, pairs: &[(Key, &str)]) -> Self {
+        self.insert_many_loaded(pairs);
+        self
     }
 
-    /// Gets a handle to a loaded asset, ensuring it's loaded if it was added lazily.
-    pub fn get(&self, key: Key) -> Option<Handle<Asset>> {
+    /// Checks if an asset is registered under a key, whether it's lazy or loaded.
+    pub fn contains_key(&self, key: Key) -> bool {
+        self.assets.read().unwrap().contains_key(&key)
+    }
+
+    /// Gets the path an asset was registered with, whether it's lazy or loaded.
+    pub fn path_of(&self, key: Key) -> Option<String> {
+        self.assets
+            .read()
+            .unwrap()
+            .get(&key)
+            .map(|asset| asset.path().to_owned())
+    }
+
+    /// Gets the number of assets registered with the manager.
+    pub fn len(&self) -> usize {
+        self.assets.read().unwrap().len()
+    }
+
+    /// Checks if no assets are registered with the manager.
+    pub fn is_empty(&self) -> bool {
+        self.assets.read().unwrap().is_empty()
+    }
+
+    /// Gets the keys of every registered asset.
+    pub fn keys(&self) -> Vec<Key> {
+        self.assets.read().unwrap().keys().cloned().collect()
+    }
+
+    /// Gets the key of every registered asset along with whether it's currently lazy or loaded.
+    pub fn entries(&self) -> Vec<(Key, LoadStyle)> {
+        self.assets
+            .read()
+            .unwrap()
+            .iter()
+            .map(|(key, asset)| {
+                let style = match asset {
+                    AssetHandle::Lazy(_) => LoadStyle::Lazy,
+                    AssetHandle::Loaded { .. } => LoadStyle::Loaded,
+                };
+
+                (key.clone(), style)
+            })
+            .collect()
+    }
+
+    /// Gets the number of assets the manager can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.assets.read().unwrap().capacity()
+    }
+
+    /// Sets the strength of handles returned by `get`, `get_many` and `resolve_into`, which defaults to weak.
+    ///
+    /// The manager keeps its loaded assets alive either way, but strong handles also keep them alive after they're removed from it.
+    pub fn set_handle_strength(&self, strength: Strength) {
+        *self.strength.write().unwrap() = strength;
+    }
+
+    /// Gets the strength of handles returned by `get`, `get_many` and `resolve_into`.
+    pub fn handle_strength(&self) -> Strength {
+        *self.strength.read().unwrap()
+    }
+
+    /// Sets whether bulk removals shrink the manager's capacity once it's less than a quarter full.
+    pub fn set_auto_shrink(&self, enabled: bool) {
+        self.auto_shrink.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Shares loaded handles with every other manager bound to `handle_cache`.
+    ///
+    /// A manager bound to a cache before it's inserted into an app keeps it instead of being bound to the app's.
+    pub fn set_handle_cache(&self, handle_cache: HandleCache<Asset>) {
+        *self.handle_cache.write().unwrap() = Some(handle_cache);
+    }
+
+    /// Sets a hook called with the key and path whenever a lazy asset is promoted to a loaded one.
+    ///
+    /// Assets inserted as loaded, and gets of already loaded assets, don't fire the hook.
+    /// The hook is fired once the manager's lock is released, so it may call back into the manager,
+    /// but it must not call `set_on_promote`.
+    pub fn set_on_promote(&self, on_promote: PromoteHook<Key>) {
+        *self.on_promote.write().unwrap() = Some(on_promote);
+    }
+
+    /// Sets the clock timestamping loads for `load_durations` and accesses for `shed`, which defaults to `Instant::now`.
+    pub fn set_clock(&self, clock: Clock) {
+        *self.clock.write().unwrap() = clock;
+    }
+
+    /// Sets the asset source which future loads are read from, such as a bundled or external asset folder.
+    ///
+    /// Paths which name their own source, like `"embedded://icon.png"`, are unaffected.
+    /// Call `reload_all` to read already loaded assets from the new source.
+    pub fn set_source(&self, source: AssetSourceId<'static>) {
+        self.untyped.set_source(source.clone());
+        *self.source.write().unwrap() = source;
+    }
+
+    /// Gets the asset source which loads are read from.
+    pub fn source(&self) -> AssetSourceId<'static> {
+        self.source.read().unwrap().clone()
+    }
+
+    /// Inserts a lazy asset into the manager.
+    pub fn insert(&self, key: Key, path: &str) {
         self.assets
             .write()
             .unwrap()
-            .get_mut(&key)
-            .map(|asset| match asset {
+            .insert(key.clone(), AssetHandle::Lazy(path.to_owned()));
+        self.styles.write().unwrap().insert(key, LoadStyle::Lazy);
+    }
+
+    /// Inserts a lazy subasset into the manager, addressed by `label` within the file at `path`, such as a mesh of a glTF file.
+    ///
+    /// If `path` already has a label, it's replaced by `label`.
+    pub fn insert_labeled(
+        &self,
+        key: Key,
+        path: &str,
+        label: &str,
+    ) -> Result<(), AssetManagerError> {
+        if label.is_empty() {
+            return Err(AssetManagerError::EmptyLabel);
+        }
+
+        let path = path.split_once('#').map_or(path, |(path, _)| path);
+        self.insert(key, &format!("{path}#{label}"));
+
+        Ok(())
+    }
+
+    /// Inserts a lazy asset into the manager, recording its size in bytes for `pending_bytes`.
+    pub fn insert_with_size(&self, key: Key, path: &str, bytes: u64) {
+        self.insert(key.clone(), path);
+        self.sizes.write().unwrap().insert(key, bytes);
+    }
+
+    /// Inserts multiple lazy assets into the manager.
+    pub fn insert_many(&self, pairs: &[(Key, &str)]) {
+        self.insert_many_iter(pairs.iter().map(|(key, path)| (key.clone(), *path)));
+    }
+
+    /// Inserts multiple lazy assets into the manager from any iterable of key and path pairs.
+    pub fn insert_many_iter<'a>(&self, pairs: impl IntoIterator<Item = (Key, &'a str)>) {
+        let mut lock = self.assets.write().unwrap();
+        let mut styles = self.styles.write().unwrap();
+
+        pairs.into_iter().for_each(|(key, path)| {
+            lock.insert(key.clone(), AssetHandle::Lazy(path.to_owned()));
+            styles.insert(key, LoadStyle::Lazy);
+        });
+    }
+
+    /// Inserts multiple lazy assets into the manager, tagging each with its listed tags.
+    pub fn insert_tagged_many(&self, entries: &[(Key, &str, &[&str])]) {
+        let mut lock = self.assets.write().unwrap();
+        let mut styles = self.styles.write().unwrap();
+        let mut tags = self.tags.write().unwrap();
+
+        entries.iter().for_each(|(key, path, entry_tags)| {
+            lock.insert(key.clone(), AssetHandle::Lazy(path.to_string()));
+            styles.insert(key.clone(), LoadStyle::Lazy);
+            entry_tags.iter().for_each(|tag| {
+                tags.entry(tag.to_string()).or_default().insert(key.clone());
+            });
+        });
+    }
+
+    /// Inserts a lazy asset into the manager, which is loaded by the next call to `load_preload`.
+    pub fn insert_preload(&self, key: Key, path: &str) {
+        self.assets
+            .write()
+            .unwrap()
+            .insert(key.clone(), AssetHandle::Lazy(path.to_owned()));
+        self.styles.write().unwrap().insert(key, LoadStyle::Preload);
+    }
+
+    /// Inserts a lazy asset into the manager which is owned by `state`, and removed by `asset_manager_state_cleanup` once it exits.
+    ///
+    /// `add_state_scoped_assets` adds the cleanup system for a state.
+    pub fn insert_state_scoped<S: States>(&self, key: Key, path: &str, state: S) {
+        self.insert(key.clone(), path);
+        self.scopes.write().unwrap().insert(key, Box::new(state));
+    }
+
+    /// Inserts a loaded asset into the manager.
+    pub fn insert_loaded(&self, key: Key, path: &str) {
+        self.start_load_timer(key.clone());
+        self.assets.write().unwrap().insert(
+            key.clone(),
+            AssetHandle::Loaded {
+                path: path.to_owned(),
+                handle: self.load_path(path),
+            },
+        );
+        self.styles.write().unwrap().insert(key, LoadStyle::Loaded);
+    }
+
+    /// Inserts an asset into the manager and loads it with loader settings adjusted by `settings`.
+    ///
+    /// Settings are only applied to this load, bypassing the handle cache. If the asset is later reverted to a lazy asset,
+    /// such as by `shed` or `clear_loaded`, it's loaded again with the loader's default settings.
+    pub fn insert_loaded_with_settings<S>(
+        &self,
+        key: Key,
+        path: &str,
+        settings: impl Fn(&mut S) + Send + Sync + 'static,
+    ) where
+        S: Settings,
+    {
+        #[cfg(feature = "metrics")]
+        self.counters.loads.fetch_add(1, Ordering::Relaxed);
+        self.start_load_timer(key.clone());
+        self.assets.write().unwrap().insert(
+            key.clone(),
+            AssetHandle::Loaded {
+                path: path.to_owned(),
+                handle: self
+                    .asset_server
+                    .load_with_settings(self.source_path(path), settings),
+            },
+        );
+        self.styles.write().unwrap().insert(key, LoadStyle::Loaded);
+    }
+
+    /// Inserts an asset into the manager lazily, loaded or for preloading, as decided by `style`.
+    pub fn insert_with_style(&self, key: Key, path: &str, style: LoadStyle) {
+        match style {
+            LoadStyle::Lazy => self.insert(key, path),
+            LoadStyle::Loaded => self.insert_loaded(key, path),
+            LoadStyle::Preload => self.insert_preload(key, path),
+        }
+    }
+
+    /// Inserts multiple assets into the manager lazily, loaded or for preloading, as decided by each entry's style.
+    pub fn insert_many_with_styles(&self, entries: &[(Key, &str, LoadStyle)]) {
+        let mut lock = self.assets.write().unwrap();
+        let mut styles = self.styles.write().unwrap();
+
+        entries.iter().for_each(|(key, path, style)| {
+            let asset = match style {
+                LoadStyle::Lazy | LoadStyle::Preload => AssetHandle::Lazy(path.to_string()),
+                LoadStyle::Loaded => {
+                    self.start_load_timer(key.clone());
+                    AssetHandle::Loaded {
+                        path: path.to_string(),
+                        handle: self.load_path(path),
+                    }
+                }
+            };
+            lock.insert(key.clone(), asset);
+            styles.insert(key.clone(), *style);
+        });
+    }
+
+    /// Inserts multiple loaded assets into the manager.
+    pub fn insert_many_loaded(&self, pairs: &[(Key, &str)]) {
+        let mut lock = self.assets.write().unwrap();
+        let mut styles = self.styles.write().unwrap();
+
+        pairs.iter().for_each(|(key, path)| {
+            self.start_load_timer(key.clone());
+            lock.insert(
+                key.clone(),
+                AssetHandle::Loaded {
+                    path: path.to_string(),
+                    handle: self.load_path(path),
+                },
+            );
+            styles.insert(key.clone(), LoadStyle::Loaded);
+        });
+    }
+
+    /// Loads an asset if it was added lazily, doing nothing if it is already loaded.
+    pub fn load(&self, key: Key) {
+        if let Some(asset) = self.assets.write().unwrap().get_mut(&key) {
+            match asset {
                 AssetHandle::Lazy(path) => {
-                    let handle = self.asset_server.load(path.to_owned());
-                    *asset = AssetHandle::Loaded(handle.clone_weak());
+                    *asset = AssetHandle::Loaded {
+                        handle: self.promote(key, path),
+                        path: std::mem::take(path),
+                    }
+                }
+                AssetHandle::Loaded { .. } => {}
+            }
+        }
+
+        self.fire_promoted();
+    }
+
+    /// Loads an asset if it was added lazily, returning whether it's registered at all.
+    pub fn ensure_loading(&self, key: Key) -> bool {
+        let mut lock = self.assets.write().unwrap();
+        let Some(asset) = lock.get_mut(&key) else {
+            return false;
+        };
+
+        if let AssetHandle::Lazy(path) = asset {
+            *asset = AssetHandle::Loaded {
+                handle: self.promote(key, path),
+                path: std::mem::take(path),
+            }
+        }
+        drop(lock);
+
+        self.fire_promoted();
+        true
+    }
+
+    /// Loads multiple assets if they were added lazily, doing nothing if they are already loaded.
+    pub fn load_many(&self, keys: &[Key]) {
+        let mut lock = self.assets.write().unwrap();
+
+        keys.iter().for_each(|key| {
+            if let Some(asset) = lock.get_mut(key) {
+                match asset {
+                    AssetHandle::Lazy(path) => {
+                        *asset = AssetHandle::Loaded {
+                            handle: self.promote(key.clone(), path),
+                            path: std::mem::take(path),
+                        }
+                    }
+                    AssetHandle::Loaded { .. } => {}
+                }
+            }
+        });
+        drop(lock);
+
+        self.fire_promoted();
+    }
+
+    /// Loads every lazy asset, doing nothing for assets which are already loaded.
+    pub fn load_all(&self) {
+        self.load_all_except(&[]);
+    }
+
+    /// Loads every lazy asset whose key isn't in `skip`.
+    pub fn load_all_except(&self, skip: &[Key]) {
+        let mut lock = self.assets.write().unwrap();
+
+        lock.iter_mut()
+            .filter(|(key, _)| !skip.contains(key))
+            .for_each(|(key, asset)| {
+                if let AssetHandle::Lazy(path) = asset {
+                    *asset = AssetHandle::Loaded {
+                        handle: self.promote(key.clone(), path),
+                        path: std::mem::take(path),
+                    }
+                }
+            });
+        drop(lock);
+
+        self.fire_promoted();
+    }
+
+    /// Loads every asset inserted with `insert_preload` which is still lazy.
+    pub fn load_preload(&self) {
+        let keys: Vec<Key> = self
+            .styles
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|(_, style)| **style == LoadStyle::Preload)
+            .map(|(key, _)| key.clone())
+            .collect();
+
+        self.load_many(&keys);
+    }
+
+    /// Counts the assets in each load state in a single pass.
+    pub fn state_counts(&self) -> StateCounts {
+        self.assets
+            .read()
+            .unwrap()
+            .values()
+            .fold(StateCounts::default(), |mut counts, asset| {
+                match asset
+                    .handle()
+                    .and_then(|handle| self.asset_server.get_load_state(handle.id()))
+                {
+                    Some(LoadState::Loading) => counts.loading += 1,
+                    Some(LoadState::Loaded) => counts.loaded += 1,
+                    Some(LoadState::Failed) => counts.failed += 1,
+                    Some(LoadState::NotLoaded) | None => counts.not_started += 1,
+                }
+
+                counts
+            })
+    }
 
-                    handle
+    /// Counts the requested assets which have finished loading along with their dependencies, and the requested assets in total, in that order.
+    ///
+    /// Lazy assets which were never requested aren't counted.
+    pub fn progress(&self) -> (usize, usize) {
+        self.assets
+            .read()
+            .unwrap()
+            .values()
+            .filter_map(AssetHandle::handle)
+            .fold((0, 0), |(loaded, total), handle| {
+                match self
+                    .asset_server
+                    .recursive_dependency_load_state(handle.id())
+                {
+                    RecursiveDependencyLoadState::Loaded => (loaded + 1, total + 1),
+                    _ => (loaded, total + 1),
                 }
-                AssetHandle::Loaded(handle) => handle.clone_weak(),
             })
     }
 
+    /// Gets the fraction of requested assets which have finished loading, as counted by `progress`.
+    ///
+    /// This is 0 when no assets have been requested.
+    pub fn progress_fraction(&self) -> f32 {
+        let (loaded, total) = self.progress();
+
+        loaded as f32 / total.max(1) as f32
+    }
+
+    /// Counts the assets which were inserted lazily, loaded and for preloading, in that order.
+    ///
+    /// This reflects how assets were inserted, not whether they have since been loaded.
+    pub fn style_breakdown(&self) -> (usize, usize, usize) {
+        self.styles
+            .read()
+            .unwrap()
+            .values()
+            .fold((0, 0, 0), |(lazy, loaded, preload), style| match style {
+                LoadStyle::Lazy => (lazy + 1, loaded, preload),
+                LoadStyle::Loaded => (lazy, loaded + 1, preload),
+                LoadStyle::Preload => (lazy, loaded, preload + 1),
+            })
+    }
+
+    /// Loads multiple assets if they were added lazily, mapping each key to whether this call loaded it.
+    ///
+    /// Keys which were already loaded or aren't registered map to `false`.
+    pub fn load_many_detailed(&self, keys: &[Key]) -> HashMap<Key, bool> {
+        let mut lock = self.assets.write().unwrap();
+        let loaded = keys
+            .iter()
+            .map(|key| {
+                let loaded = match lock.get_mut(key) {
+                    Some(asset) => match asset {
+                        AssetHandle::Lazy(path) => {
+                            *asset = AssetHandle::Loaded {
+                                handle: self.promote(key.clone(), path),
+                                path: std::mem::take(path),
+                            };
+
+                            true
+                        }
+                        AssetHandle::Loaded { .. } => false,
+                    },
+                    None => false,
+                };
+
+                (key.clone(), loaded)
+            })
+            .collect();
+        drop(lock);
+
+        self.fire_promoted();
+        loaded
+    }
+
+    /// Gets a handle to a loaded asset, ensuring it's loaded if it was added lazily.
+    ///
+    /// The handle is weak unless `set_handle_strength` says otherwise. The manager holds a strong handle itself,
+    /// so the asset stays loaded while it's registered; use `get_strong` to keep it alive independently of the manager.
+    pub fn get(&self, key: Key) -> Option<Handle<Asset>> {
+        self.try_get(key).unwrap()
+    }
+
+    /// Gets a handle to a loaded asset like `get`, ensuring it's loaded if it was added lazily.
+    ///
+    /// The manager's assets live behind a lock, so it can't implement `Index` by handing out references; this is the shorthand instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics with the key's `Debug` representation if no asset is registered under it.
+    pub fn expect(&self, key: Key) -> Handle<Asset>
+    where
+        Key: Debug,
+    {
+        match self.get(key.clone()) {
+            Some(handle) => handle,
+            None => panic!("no asset is registered under the key {key:?}"),
+        }
+    }
+
+    /// Gets a handle to a loaded asset like `get`, returning an error instead of panicking if the assets lock is poisoned.
+    pub fn try_get(&self, key: Key) -> Result<Option<Handle<Asset>>, AssetManagerError> {
+        self.resolve_shared(key, self.handle_strength())
+    }
+
+    /// Gets a strong handle to a loaded asset, ensuring it's loaded if it was added lazily.
+    ///
+    /// Unlike the handles returned by `get`, this keeps the asset alive even after it's removed from the manager.
+    pub fn get_strong(&self, key: Key) -> Option<Handle<Asset>> {
+        self.resolve_shared(key, Strength::Strong).unwrap()
+    }
+
+    /// Gets a handle to an asset, inserting it as a loaded asset from `path` if its key isn't registered yet.
+    ///
+    /// If the key is already registered, its entry is kept as is and `path` is ignored, even if the entry was registered with a different path.
+    /// Lazy entries are loaded like with `get`.
+    pub fn get_or_insert(&self, key: Key, path: &str) -> Handle<Asset> {
+        let mut lock = self.assets.write().unwrap();
+        let handle = match lock.get_mut(&key) {
+            Some(asset) => self.resolve(key, asset, self.handle_strength()),
+            None => {
+                self.start_load_timer(key.clone());
+                let handle = self.load_path(path);
+                let resolved = match self.handle_strength() {
+                    Strength::Weak => handle.clone_weak(),
+                    Strength::Strong => handle.clone(),
+                };
+                lock.insert(
+                    key.clone(),
+                    AssetHandle::Loaded {
+                        path: path.to_owned(),
+                        handle,
+                    },
+                );
+                self.styles.write().unwrap().insert(key, LoadStyle::Loaded);
+
+                resolved
+            }
+        };
+        drop(lock);
+
+        self.fire_promoted();
+        handle
+    }
+
+    /// Sets the placeholder handle returned by `get_or_fallback` while assets are still loading.
+    pub fn set_fallback(&self, handle: Handle<Asset>) {
+        *self.fallback.write().unwrap() = Some(handle);
+    }
+
+    /// Gets a handle to an asset if it has finished loading, or the fallback set by `set_fallback` otherwise,
+    /// ensuring it's loaded if it was added lazily.
+    ///
+    /// Without a fallback, the asset's handle is returned even while it's loading, and the default handle is returned for an unregistered key.
+    pub fn get_or_fallback(&self, key: Key) -> Handle<Asset> {
+        let handle = self.get(key);
+        let loaded = handle.as_ref().is_some_and(|handle| {
+            self.asset_server.get_load_state(handle.id()) == Some(LoadState::Loaded)
+        });
+
+        match (handle, self.fallback.read().unwrap().as_ref()) {
+            (Some(handle), _) if loaded => handle,
+            (_, Some(fallback)) if self.handle_strength() == Strength::Weak => {
+                fallback.clone_weak()
+            }
+            (_, Some(fallback)) => fallback.clone(),
+            (handle, None) => handle.unwrap_or_default(),
+        }
+    }
+
+    /// Gets a handle to a loaded asset along with whether it was already loaded, ensuring it's loaded if it was added lazily.
+    ///
+    /// The flag is true for a hit, where the asset was already loaded, and false when a lazy asset had to be promoted.
+    pub fn get_instrumented(&self, key: Key) -> Option<(Handle<Asset>, bool)> {
+        let result = self.assets.write().unwrap().get_mut(&key).map(|asset| {
+            let hit = asset.is_loaded();
+            (self.resolve(key, asset, self.handle_strength()), hit)
+        });
+
+        self.fire_promoted();
+        result
+    }
+
+    /// Gets a handle to a loaded asset along with its current load state, ensuring it's loaded if it was added lazily.
+    pub fn get_with_state(&self, key: Key) -> Option<(Handle<Asset>, LoadState)> {
+        let handle = self.get(key)?;
+        let state = self
+            .asset_server
+            .get_load_state(handle.id())
+            .unwrap_or(LoadState::NotLoaded);
+
+        Some((handle, state))
+    }
+
+    /// Loads an asset's path from another asset source, returning a strong handle without changing the stored asset.
+    pub fn get_from(&self, key: Key, source: AssetSourceId) -> Option<Handle<Asset>> {
+        let path = self.assets.read().unwrap().get(&key)?.path().to_owned();
+
+        Some(self.load_path_from(&source, &path))
+    }
+
     /// Gets multiple handles to loaded assets, ensuring they're loaded if they were added lazily.
     pub fn get_many(&self, keys: &[Key]) -> Vec<Handle<Asset>> {
+        self.get_many_with(keys, self.handle_strength())
+    }
+
+    /// Gets multiple handles to loaded assets of the given strength, ensuring they're loaded if they were added lazily.
+    pub fn get_many_with(&self, keys: &[Key], strength: Strength) -> Vec<Handle<Asset>> {
+        let lock = self.assets.read().unwrap();
+        if keys
+            .iter()
+            .all(|key| lock.get(key).is_none_or(AssetHandle::is_loaded))
+        {
+            return keys
+                .iter()
+                .filter_map(|key| {
+                    lock.get(key)
+                        .map(|asset| self.resolve_loaded(key.clone(), asset, strength))
+                })
+                .collect();
+        }
+        drop(lock);
+
         let mut lock = self.assets.write().unwrap();
-        let get_asset = |key| {
-            lock.get_mut(key).map(|asset| match asset {
-                AssetHandle::Lazy(path) => {
-                    let handle = self.asset_server.load(path.to_owned());
-                    *asset = AssetHandle::Loaded(handle.clone_weak());
+        let handles = keys
+            .iter()
+            .filter_map(|key| {
+                lock.get_mut(key)
+                    .map(|asset| self.resolve(key.clone(), asset, strength))
+            })
+            .collect();
+        drop(lock);
 
-                    handle
-                }
-                AssetHandle::Loaded(handle) => handle.clone_weak(),
+        self.fire_promoted();
+        handles
+    }
+
+    /// Gets handles to loaded assets for any iterable of keys, ensuring they're loaded if they were added lazily.
+    ///
+    /// This is the same as `get_many_iter`.
+    pub fn get_iter_collected(&self, keys: impl IntoIterator<Item = Key>) -> Vec<Handle<Asset>> {
+        self.get_many_iter(keys)
+    }
+
+    /// Gets handles to loaded assets for any iterable of keys, ensuring they're loaded if they were added lazily.
+    ///
+    /// Unlike `get_many`, this always takes the assets write lock, since the keys can only be iterated once.
+    pub fn get_many_iter(&self, keys: impl IntoIterator<Item = Key>) -> Vec<Handle<Asset>> {
+        let strength = self.handle_strength();
+        let mut lock = self.assets.write().unwrap();
+        let handles = keys
+            .into_iter()
+            .filter_map(|key| {
+                let asset = lock.get_mut(&key)?;
+                Some(self.resolve(key, asset, strength))
             })
+            .collect();
+        drop(lock);
+
+        self.fire_promoted();
+        handles
+    }
+
+    /// Gets a weak handle to an asset without loading it, returning `None` for missing keys and lazy assets.
+    pub fn peek(&self, key: Key) -> Option<Handle<Asset>> {
+        self.assets
+            .read()
+            .unwrap()
+            .get(&key)?
+            .handle()
+            .map(Handle::clone_weak)
+    }
+
+    /// Gets a handle to an asset which is already loaded, such as after `load_all`, returning `None` for missing keys and lazy assets.
+    ///
+    /// Unlike `get`, this never promotes lazy assets, so it only ever takes the assets read lock.
+    /// Unlike `peek`, the handle's strength follows `set_handle_strength` and the access is recorded for `shed`.
+    pub fn get_cached(&self, key: Key) -> Option<Handle<Asset>> {
+        let lock = self.assets.read().unwrap();
+        let asset = lock.get(&key).filter(|asset| asset.is_loaded())?;
+
+        Some(self.resolve_loaded(key, asset, self.handle_strength()))
+    }
+
+    /// Gets weak handles to multiple assets without loading them, returning `None` for missing keys and lazy assets.
+    ///
+    /// The handles are positionally aligned with `keys`.
+    pub fn peek_many(&self, keys: &[Key]) -> Vec<Option<Handle<Asset>>> {
+        let lock = self.assets.read().unwrap();
+
+        keys.iter()
+            .map(|key| lock.get(key)?.handle().map(Handle::clone_weak))
+            .collect()
+    }
+
+    /// Fills `out` with handles to loaded assets, ensuring they're loaded if they were added lazily.
+    ///
+    /// `out` is cleared first, so it can be reused across calls without reallocating.
+    pub fn resolve_into(&self, keys: &[Key], out: &mut HashMap<Key, Handle<Asset>>) {
+        let mut lock = self.assets.write().unwrap();
+        out.clear();
+
+        keys.iter().for_each(|key| {
+            if let Some(asset) = lock.get_mut(key) {
+                out.insert(
+                    key.clone(),
+                    self.resolve(key.clone(), asset, self.handle_strength()),
+                );
+            }
+        });
+        drop(lock);
+
+        self.fire_promoted();
+    }
+
+    /// Loads multiple assets, returning strong handles which keep them alive and a closure reporting their progress.
+    ///
+    /// The closure returns the fraction of the assets which have finished loading along with their dependencies, from `0.0` to `1.0`.
+    pub fn preload_tracked(&self, keys: &[Key]) -> (Vec<Handle<Asset>>, impl Fn() -> f32 + '_) {
+        let handles: Vec<Handle<Asset>> = self
+            .load_strong(keys)
+            .into_iter()
+            .map(|(_, handle)| handle)
+            .collect();
+        let ids: Vec<_> = handles.iter().map(Handle::id).collect();
+
+        let progress = move || {
+            if ids.is_empty() {
+                return 1.0;
+            }
+
+            let loaded = ids
+                .iter()
+                .filter(|id| {
+                    self.asset_server.recursive_dependency_load_state(**id)
+                        == RecursiveDependencyLoadState::Loaded
+                })
+                .count();
+
+            loaded as f32 / ids.len() as f32
         };
 
-        keys.iter().filter_map(get_asset).collect()
+        (handles, progress)
+    }
+
+    /// Gets the load state of an asset without promoting it, returning `None` for lazy assets.
+    pub fn load_state(&self, key: Key) -> Option<LoadState> {
+        let lock = self.assets.read().unwrap();
+        let handle = lock.get(&key)?.handle()?;
+
+        self.asset_server.get_load_state(handle.id())
+    }
+
+    /// Checks if an asset has finished loading without promoting it, returning false for lazy assets.
+    pub fn is_loaded(&self, key: Key) -> bool {
+        self.load_state(key) == Some(LoadState::Loaded)
+    }
+
+    /// Checks if an asset and all of its dependencies have finished loading, returning false for lazy assets.
+    pub fn is_fully_loaded(&self, key: Key) -> bool {
+        self.assets
+            .read()
+            .unwrap()
+            .get(&key)
+            .and_then(AssetHandle::handle)
+            .is_some_and(|handle| {
+                self.asset_server
+                    .recursive_dependency_load_state(handle.id())
+                    == RecursiveDependencyLoadState::Loaded
+            })
+    }
+
+    /// Watches an asset, returning a receiver of its load state transitions as delivered by `notify_observers`.
+    ///
+    /// Lazy assets have no load state, so nothing is delivered until they're loaded.
+    pub fn observe_key(&self, key: Key) -> Receiver<LoadState> {
+        let (sender, receiver) = mpsc::channel();
+        self.observers.lock().unwrap().push((key, None, sender));
+
+        receiver
+    }
+
+    /// Sends the load state of every observed asset whose state changed since the last call, dropping observers whose receivers were dropped.
+    pub fn notify_observers(&self) {
+        let mut observers = self.observers.lock().unwrap();
+        let assets = self.assets.read().unwrap();
+
+        observers.retain_mut(|(key, last, sender)| {
+            let Some(state) = assets
+                .get(key)
+                .and_then(AssetHandle::handle)
+                .and_then(|handle| self.asset_server.get_load_state(handle.id()))
+            else {
+                return true;
+            };
+
+            if *last == Some(state) {
+                return true;
+            }
+
+            *last = Some(state);
+            sender.send(state).is_ok()
+        });
+    }
+
+    /// Loads multiple assets and attaches strong handles to them to `entity` as an `AssetLease`, so despawning it releases the group.
+    ///
+    /// The manager also holds its loaded assets strongly, so they're only unloaded once they're also removed from it or reverted to lazy.
+    /// This replaces any `AssetLease` of the same asset type already attached to `entity`.
+    pub fn lease_group_for_entity(&self, keys: &[Key], commands: &mut Commands, entity: Entity) {
+        let handles = self.get_many_with(keys, Strength::Strong);
+        commands.entity(entity).insert(AssetLease(handles));
+    }
+
+    /// Records how long every asset which has finished loading since it was last loaded took to load.
+    ///
+    /// `asset_manager_events` calls this every frame, so completions are observed with frame granularity.
+    pub fn record_load_completions(&self) {
+        let now = self.now();
+        let assets = self.assets.read().unwrap();
+
+        self.load_times
+            .lock()
+            .unwrap()
+            .iter_mut()
+            .filter(|(_, (_, duration))| duration.is_none())
+            .for_each(|(key, (started, duration))| {
+                let loaded = assets
+                    .get(key)
+                    .and_then(AssetHandle::handle)
+                    .is_some_and(|handle| {
+                        self.asset_server.get_load_state(handle.id()) == Some(LoadState::Loaded)
+                    });
+                if loaded {
+                    *duration = Some(now.saturating_duration_since(*started));
+                }
+            });
+    }
+
+    /// Gets how long each asset took to load, as recorded by `record_load_completions`.
+    ///
+    /// Completions are only recorded while `asset_manager_events` is scheduled, such as by `add_asset_manager_events`,
+    /// or `record_load_completions` is called manually. Assets which haven't finished loading are left out.
+    pub fn load_durations(&self) -> Vec<(Key, Duration)> {
+        self.load_times
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(|(key, (_, duration))| Some((key.clone(), (*duration)?)))
+            .collect()
+    }
+
+    /// Waits for multiple assets and their dependencies to finish loading, ensuring they're loaded if they were added lazily.
+    ///
+    /// Returns strong handles to the assets once all of them have loaded, or the keys of the assets which failed to load.
+    /// Load states only advance while the app updates, so this cooperatively yields and must be awaited from a task.
+    pub async fn wait_all(&self, keys: &[Key]) -> Result<Vec<Handle<Asset>>, Vec<Key>> {
+        let handles = self.load_strong(keys);
+
+        loop {
+            let mut loaded = true;
+            let mut failed = vec![];
+
+            for (key, handle) in &handles {
+                match self
+                    .asset_server
+                    .recursive_dependency_load_state(handle.id())
+                {
+                    RecursiveDependencyLoadState::Loaded => {}
+                    RecursiveDependencyLoadState::Failed => failed.push(key.clone()),
+                    _ => loaded = false,
+                }
+            }
+
+            if !failed.is_empty() {
+                return Err(failed);
+            }
+
+            if loaded {
+                return Ok(handles.into_iter().map(|(_, handle)| handle).collect());
+            }
+
+            futures_lite::future::yield_now().await;
+        }
+    }
+
+    /// Waits for an asset to finish loading, ensuring it's loaded if it was added lazily.
+    ///
+    /// Returns a strong handle to the asset once it has loaded, without waiting for its dependencies like `wait_all`.
+    /// Load states only advance while the app updates, so this cooperatively yields and must be awaited from a task.
+    pub async fn wait_for(&self, key: Key) -> Result<Handle<Asset>, AssetManagerError> {
+        let (_, handle) = self
+            .load_strong(&[key])
+            .pop()
+            .ok_or(AssetManagerError::MissingKey)?;
+
+        loop {
+            match self.asset_server.get_load_state(handle.id()) {
+                Some(LoadState::Loaded) => return Ok(handle),
+                Some(LoadState::Failed) => return Err(AssetManagerError::LoadFailed),
+                _ => futures_lite::future::yield_now().await,
+            }
+        }
+    }
+
+    /// Sums the sizes recorded with `insert_with_size` of every asset which hasn't finished loading.
+    pub fn pending_bytes(&self) -> u64 {
+        let assets = self.assets.read().unwrap();
+
+        self.sizes
+            .read()
+            .unwrap()
+            .iter()
+            .filter_map(|(key, bytes)| match assets.get(key)? {
+                AssetHandle::Lazy(_) => Some(bytes),
+                AssetHandle::Loaded { handle, .. } => {
+                    match self.asset_server.get_load_state(handle.id()) {
+                        Some(LoadState::Loaded) => None,
+                        _ => Some(bytes),
+                    }
+                }
+            })
+            .sum()
+    }
+
+    /// Calls `f` with every key and asset while holding the read lock.
+    ///
+    /// Calling methods of the manager which write from within `f` will deadlock.
+    pub fn for_each(&self, mut f: impl FnMut(&Key, &AssetHandle<Asset>)) {
+        self.assets
+            .read()
+            .unwrap()
+            .iter()
+            .for_each(|(key, asset)| f(key, asset));
+    }
+
+    /// Calls `f` with the map entry of an asset while holding the write lock, for custom insert or modify logic.
+    ///
+    /// Calling other methods of the manager from within `f` will deadlock.
+    pub fn with_entry<R>(
+        &self,
+        key: Key,
+        f: impl FnOnce(Entry<'_, Key, AssetHandle<Asset>, DefaultHashBuilder>) -> R,
+    ) -> R {
+        f(self.assets.write().unwrap().entry(key))
+    }
+
+    /// Removes an asset from the manager, returning its handle if it was loaded.
+    ///
+    /// The handle is the one the manager stored, so it's strong if the manager held the asset strongly.
+    pub fn remove(&self, key: Key) -> Option<Handle<Asset>> {
+        self.remove_many(std::slice::from_ref(&key)).pop()
+    }
+
+    /// Removes multiple assets from the manager, returning the handles of those which were loaded.
+    ///
+    /// Untyped assets registered under the keys are removed too.
+    pub fn remove_many(&self, keys: &[Key]) -> Vec<Handle<Asset>> {
+        keys.iter().for_each(|key| {
+            self.untyped.remove(key.clone());
+        });
+        let mut lock = self.assets.write().unwrap();
+        let handles = keys
+            .iter()
+            .filter_map(|key| match lock.remove(key)? {
+                AssetHandle::Lazy(_) => None,
+                AssetHandle::Loaded { handle, .. } => Some(handle),
+            })
+            .collect();
+        self.forget(keys);
+        self.shrink_if_sparse(&mut lock);
+
+        handles
+    }
+
+    /// Moves an asset to another key, keeping its handle along with its size, style, tags and other recorded state.
+    ///
+    /// An untyped asset registered under `from` is moved too. Any asset already registered under `to` is overwritten.
+    /// Returns `false` if no asset is registered under `from`.
+    pub fn rename_key(&self, from: Key, to: Key) -> bool {
+        let renamed_untyped = self.untyped.rename_key(from.clone(), to.clone());
+        let mut lock = self.assets.write().unwrap();
+        let Some(asset) = lock.remove(&from) else {
+            return renamed_untyped;
+        };
+        lock.insert(to.clone(), asset);
+        if from == to {
+            return true;
+        }
+        self.forget(std::slice::from_ref(&to));
+
+        let mut sizes = self.sizes.write().unwrap();
+        let mut styles = self.styles.write().unwrap();
+        let mut accessed = self.accessed.lock().unwrap();
+        let mut load_times = self.load_times.lock().unwrap();
+        let mut scopes = self.scopes.write().unwrap();
+        let mut tags = self.tags.write().unwrap();
+
+        if let Some(bytes) = sizes.remove(&from) {
+            sizes.insert(to.clone(), bytes);
+        }
+
+        if let Some(style) = styles.remove(&from) {
+            styles.insert(to.clone(), style);
+        }
+
+        if let Some(instant) = accessed.remove(&from) {
+            accessed.insert(to.clone(), instant);
+        }
+
+        if let Some(timing) = load_times.remove(&from) {
+            load_times.insert(to.clone(), timing);
+        }
+
+        if let Some(state) = scopes.remove(&from) {
+            scopes.insert(to.clone(), state);
+        }
+
+        tags.values_mut().for_each(|tagged| {
+            if tagged.remove(&from) {
+                tagged.insert(to.clone());
+            }
+        });
+
+        true
+    }
+
+    /// Removes every asset from the manager, including untyped assets.
+    pub fn clear(&self) {
+        let mut lock = self.assets.write().unwrap();
+        let keys: Vec<Key> = lock.drain().map(|(key, _)| key).collect();
+        self.forget(&keys);
+        self.untyped.clear();
+    }
+
+    /// Reverts every loaded asset back to a lazy asset, keeping its key and path so it can be loaded again later.
+    ///
+    /// This releases the manager's handles, so assets nothing else holds strongly are unloaded.
+    pub fn clear_loaded(&self) {
+        self.assets.write().unwrap().values_mut().for_each(|asset| {
+            if let AssetHandle::Loaded { path, .. } = asset {
+                *asset = AssetHandle::Lazy(std::mem::take(path));
+            }
+        });
+    }
+
+    /// Removes every asset whose key and path match `pred`, returning how many were removed.
+    ///
+    /// Untyped assets are matched and counted too.
+    pub fn remove_if(&self, pred: impl Fn(&Key, &str) -> bool) -> usize {
+        let removed_untyped = self.untyped.remove_if(&pred);
+        let mut lock = self.assets.write().unwrap();
+        let removed: Vec<Key> = lock
+            .extract_if(|key, asset| pred(key, asset.path()))
+            .map(|(key, _)| key)
+            .collect();
+        self.forget(&removed);
+        self.shrink_if_sparse(&mut lock);
+
+        removed.len() + removed_untyped
+    }
+
+    /// Removes every asset owned by `state`, as inserted by `insert_state_scoped`, returning how many were removed.
+    pub fn remove_state_scoped<S: States>(&self, state: &S) -> usize {
+        let owned: HashSet<Key> = self
+            .scopes
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|(_, owner)| owner.downcast_ref::<S>() == Some(state))
+            .map(|(key, _)| key.clone())
+            .collect();
+
+        self.remove_if(|key, _| owned.contains(key))
+    }
+
+    /// Tags an asset, so it can be operated on along with every other asset sharing the tag.
+    ///
+    /// An asset may have any number of tags.
+    pub fn add_tag(&self, key: Key, tag: &str) {
+        self.tags
+            .write()
+            .unwrap()
+            .entry(tag.to_owned())
+            .or_default()
+            .insert(key);
+    }
+
+    /// Gets the keys of every asset with a tag.
+    pub fn keys_with_tag(&self, tag: &str) -> Vec<Key> {
+        self.tags
+            .read()
+            .unwrap()
+            .get(tag)
+            .map(|keys| keys.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Removes every tag from every asset, leaving the assets themselves registered.
+    pub fn clear_tags(&self) {
+        self.tags.write().unwrap().clear();
+    }
+
+    /// Inserts a lazy asset into the manager as a member of a group, so it can be loaded and unloaded along with the rest of the group.
+    ///
+    /// Groups are tags, so an asset may belong to any number of groups, and groups work with every tag method, such as `split_off_tag`.
+    pub fn insert_in_group(&self, key: Key, path: &str, group: &str) {
+        self.insert(key.clone(), path);
+        self.add_tag(key, group);
+    }
+
+    /// Gets the keys of every asset in a group.
+    pub fn keys_in_group(&self, group: &str) -> Vec<Key> {
+        self.keys_with_tag(group)
+    }
+
+    /// Loads every asset in a group which was added lazily.
+    pub fn load_group(&self, group: &str) {
+        self.load_many(&self.keys_in_group(group));
+    }
+
+    /// Reverts every loaded asset in a group back to a lazy asset, keeping it registered so it can be loaded again later.
+    ///
+    /// Assets in several groups are unloaded even if another of their groups is still in use.
+    pub fn unload_group(&self, group: &str) {
+        let keys = self.keys_in_group(group);
+        let mut lock = self.assets.write().unwrap();
+
+        keys.iter().for_each(|key| {
+            if let Some(asset) = lock.get_mut(key) {
+                if let AssetHandle::Loaded { path, .. } = asset {
+                    *asset = AssetHandle::Lazy(std::mem::take(path));
+                }
+            }
+        });
+    }
+
+    /// Removes every asset with a tag from the manager, returning them in a new manager bound to the same asset server.
+    ///
+    /// Moved assets keep their handles, sizes and tags, so dropping the returned manager releases them together.
+    /// Tagged untyped assets are moved too.
+    pub fn split_off_tag(&self, tag: &str) -> AssetManager<Key, Asset> {
+        let mut split = Self::new(self.asset_server.clone());
+        *split.handle_cache.write().unwrap() = self.handle_cache.read().unwrap().clone();
+
+        let mut lock = self.assets.write().unwrap();
+        let mut sizes = self.sizes.write().unwrap();
+        let mut styles = self.styles.write().unwrap();
+        let mut accessed = self.accessed.lock().unwrap();
+        let mut load_times = self.load_times.lock().unwrap();
+        let mut scopes = self.scopes.write().unwrap();
+        let mut tags = self.tags.write().unwrap();
+        let keys = tags.get(tag).cloned().unwrap_or_default();
+        split.untyped = self
+            .untyped
+            .split_off(&keys.iter().cloned().collect::<Vec<Key>>());
+
+        {
+            let mut split_lock = split.assets.write().unwrap();
+            let mut split_sizes = split.sizes.write().unwrap();
+            let mut split_styles = split.styles.write().unwrap();
+            let mut split_accessed = split.accessed.lock().unwrap();
+            let mut split_load_times = split.load_times.lock().unwrap();
+            let mut split_scopes = split.scopes.write().unwrap();
+            let mut split_tags = split.tags.write().unwrap();
+
+            for key in &keys {
+                if let Some(asset) = lock.remove(key) {
+                    split_lock.insert(key.clone(), asset);
+                }
+
+                if let Some(bytes) = sizes.remove(key) {
+                    split_sizes.insert(key.clone(), bytes);
+                }
+
+                if let Some(style) = styles.remove(key) {
+                    split_styles.insert(key.clone(), style);
+                }
+
+                if let Some(instant) = accessed.remove(key) {
+                    split_accessed.insert(key.clone(), instant);
+                }
+
+                if let Some(timing) = load_times.remove(key) {
+                    split_load_times.insert(key.clone(), timing);
+                }
+
+                if let Some(state) = scopes.remove(key) {
+                    split_scopes.insert(key.clone(), state);
+                }
+            }
+
+            for (tag, tagged) in tags.iter_mut() {
+                let moved: HashSet<Key> = tagged.intersection(&keys).cloned().collect();
+                if !moved.is_empty() {
+                    tagged.retain(|key| !moved.contains(key));
+                    split_tags.insert(tag.to_owned(), moved);
+                }
+            }
+        }
+        tags.retain(|_, keys| !keys.is_empty());
+        self.shrink_if_sparse(&mut lock);
+
+        split
+    }
+
+    /// Gets a handle to an asset, blocking the current thread until it has loaded or `timeout` elapses.
+    ///
+    /// Load states are only updated while the app is running, so this must not be called from a system
+    /// on the thread driving the app. It is intended for tooling which updates the app elsewhere.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn get_blocking(
+        &self,
+        key: Key,
+        timeout: Duration,
+    ) -> Result<Handle<Asset>, AssetManagerError> {
+        let start = Instant::now();
+        let handle = self.get(key).ok_or(AssetManagerError::MissingKey)?;
+
+        loop {
+            match self.asset_server.get_load_state(handle.id()) {
+                Some(LoadState::Loaded) => return Ok(handle),
+                Some(LoadState::Failed) => return Err(AssetManagerError::LoadFailed),
+                _ if start.elapsed() >= timeout => return Err(AssetManagerError::Timeout),
+                _ => std::thread::sleep(Duration::from_millis(1)),
+            }
+        }
+    }
+
+    /// Loads every loaded asset again from the current asset source.
+    ///
+    /// Assets are reloaded in place, keeping their handle ids so anything using them picks up the new data.
+    /// Only assets whose asset source changed since they were loaded get a fresh handle, from the new source.
+    pub fn reload_all(&self) {
+        self.assets
+            .write()
+            .unwrap()
+            .values_mut()
+            .for_each(|asset| self.reload_loaded(asset));
+    }
+
+    /// Loads an asset again from disk, or loads it if it was added lazily.
+    ///
+    /// This is a manual trigger; Bevy watches asset files for changes separately when its file watcher is enabled.
+    /// Loaded assets are reloaded in place like with `reload_all`.
+    pub fn reload(&self, key: Key) {
+        self.reload_many(&[key]);
+    }
+
+    /// Loads multiple assets again from disk, or loads them if they were added lazily.
+    pub fn reload_many(&self, keys: &[Key]) {
+        let mut lock = self.assets.write().unwrap();
+
+        keys.iter().for_each(|key| {
+            if let Some(asset) = lock.get_mut(key) {
+                match asset {
+                    AssetHandle::Lazy(path) => {
+                        *asset = AssetHandle::Loaded {
+                            handle: self.promote(key.clone(), path),
+                            path: std::mem::take(path),
+                        }
+                    }
+                    AssetHandle::Loaded { .. } => self.reload_loaded(asset),
+                }
+            }
+        });
+        drop(lock);
+
+        self.fire_promoted();
+    }
+
+    /// Remaps the paths of assets for which `f` returns a new path, leaving the rest unchanged.
+    ///
+    /// Loaded assets are loaded again from their new paths, replacing their handles.
+    pub fn remap_paths(&self, f: impl Fn(&Key, &str) -> Option<String>) {
+        self.assets
+            .write()
+            .unwrap()
+            .iter_mut()
+            .for_each(|(key, asset)| {
+                let Some(new_path) = f(key, asset.path()) else {
+                    return;
+                };
+
+                match asset {
+                    AssetHandle::Lazy(path) => *path = new_path,
+                    AssetHandle::Loaded { path, handle } => {
+                        *handle = self.load_path(&new_path);
+                        *path = new_path;
+                    }
+                }
+            });
+    }
+
+    /// Gets the keys of every loaded asset which failed to load.
+    pub fn failed_keys(&self) -> Vec<Key> {
+        self.assets
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|(_, asset)| {
+                asset.handle().is_some_and(|handle| {
+                    self.asset_server.get_load_state(handle.id()) == Some(LoadState::Failed)
+                })
+            })
+            .map(|(key, _)| key.clone())
+            .collect()
+    }
+
+    /// Loads every asset which failed to load again, returning the keys of the retried assets.
+    ///
+    /// Assets the manager holds strongly keep their handles, while the rest are replaced with fresh handles.
+    pub fn retry_failed(&self) -> Vec<Key> {
+        self.assets
+            .write()
+            .unwrap()
+            .iter_mut()
+            .filter_map(|(key, asset)| match asset {
+                AssetHandle::Loaded { path, handle }
+                    if self.asset_server.get_load_state(handle.id()) == Some(LoadState::Failed) =>
+                {
+                    if handle.is_strong() {
+                        self.asset_server.reload(self.source_path(path));
+                    } else {
+                        *handle = self.load_path(path);
+                    }
+
+                    Some(key.clone())
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Moves the values of every loaded asset from `assets_from` into `assets_to`, replacing stored handles with strong handles into `assets_to`.
+    ///
+    /// Assets which aren't present in `assets_from` are left untouched.
+    pub fn rehome(&self, assets_from: &mut Assets<Asset>, assets_to: &mut Assets<Asset>) {
+        self.assets.write().unwrap().values_mut().for_each(|asset| {
+            if let AssetHandle::Loaded { handle, .. } = asset {
+                if let Some(value) = assets_from.remove(handle.id()) {
+                    *handle = assets_to.add(value);
+                }
+            }
+        });
+    }
+
+    /// Upgrades the stored handle of every loaded asset back to a strong handle, undoing `unpin_all`.
+    ///
+    /// Assets Bevy already unloaded while unpinned are loaded again.
+    pub fn pin_all_loaded(&self) {
+        self.assets.write().unwrap().values_mut().for_each(|asset| {
+            if let AssetHandle::Loaded { path, handle } = asset {
+                if !handle.is_strong() {
+                    *handle = self.load_path(path);
+                }
+            }
+        });
+    }
+
+    /// Downgrades the stored handle of every loaded asset to a weak handle, so Bevy unloads assets nothing else holds strongly.
+    ///
+    /// The manager holds its loaded assets strongly by default, so this is what lets `asset_manager_trim_unused` revert them
+    /// to lazy assets once they're unused. Assets loaded afterwards are held strongly again until the next call.
+    pub fn unpin_all(&self) {
+        self.assets.write().unwrap().values_mut().for_each(|asset| {
+            if let AssetHandle::Loaded { handle, .. } = asset {
+                *handle = handle.clone_weak();
+            }
+        });
+    }
+
+    /// Reverts the least recently accessed loaded assets back to lazy assets until at most `target_loaded` remain loaded.
+    ///
+    /// Assets are accessed through `get`, `get_many` and `resolve_into`; assets which were never accessed are reverted first.
+    pub fn shed(&self, target_loaded: usize) {
+        let mut lock = self.assets.write().unwrap();
+        let accessed = self.accessed.lock().unwrap();
+        let mut loaded: Vec<(Option<Instant>, Key)> = lock
+            .iter()
+            .filter(|(_, asset)| asset.is_loaded())
+            .map(|(key, _)| (accessed.get(key).copied(), key.clone()))
+            .collect();
+        if loaded.len() <= target_loaded {
+            return;
+        }
+
+        loaded.sort_unstable_by_key(|(instant, _)| *instant);
+        let excess = loaded.len() - target_loaded;
+        for (_, key) in loaded.into_iter().take(excess) {
+            if let Some(asset) = lock.get_mut(&key) {
+                if let AssetHandle::Loaded { path, .. } = asset {
+                    *asset = AssetHandle::Lazy(std::mem::take(path));
+                }
+            }
+        }
+    }
+
+    /// Reverts loaded assets with any of the given ids back to lazy assets, returning how many were reverted.
+    pub fn trim_unused(&self, ids: impl IntoIterator<Item = AssetId<Asset>>) -> usize {
+        let ids: HashSet<AssetId<Asset>> = ids.into_iter().collect();
+        if ids.is_empty() {
+            return 0;
+        }
+
+        let mut trimmed = 0;
+        self.assets
+            .write()
+            .unwrap()
+            .values_mut()
+            .for_each(|asset| match asset {
+                AssetHandle::Loaded { path, handle } if ids.contains(&handle.id()) => {
+                    *asset = AssetHandle::Lazy(std::mem::take(path));
+                    trimmed += 1;
+                }
+                _ => {}
+            });
+
+        trimmed
+    }
+
+    /// Loads lazy assets until `budget` has elapsed, returning how many were loaded.
+    ///
+    /// Time is measured with the manager's clock between `load` calls, so a load already in progress is never interrupted.
+    /// Nothing is loaded while loading is paused.
+    pub fn load_within(&self, budget: Duration) -> usize {
+        if self.is_loading_paused() {
+            return 0;
+        }
+
+        let start = self.now();
+        let mut lock = self.assets.write().unwrap();
+        let mut loaded = 0;
+
+        for (key, asset) in lock.iter_mut() {
+            let AssetHandle::Lazy(path) = asset else {
+                continue;
+            };
+            if self.now().saturating_duration_since(start) >= budget {
+                break;
+            }
+
+            *asset = AssetHandle::Loaded {
+                handle: self.promote(key.clone(), path),
+                path: std::mem::take(path),
+            };
+            loaded += 1;
+        }
+        drop(lock);
+
+        self.fire_promoted();
+        loaded
+    }
+
+    /// Pauses background loading, so `load_within` issues no new loads until `resume_loading` is called.
+    ///
+    /// Loads which are already in flight continue, and explicit calls like `load` and `get` are unaffected.
+    pub fn pause_loading(&self) {
+        self.paused.store(true, Ordering::Relaxed);
+    }
+
+    /// Resumes background loading after a call to `pause_loading`.
+    pub fn resume_loading(&self) {
+        self.paused.store(false, Ordering::Relaxed);
+    }
+
+    /// Checks whether background loading is paused.
+    pub fn is_loading_paused(&self) -> bool {
+        self.paused.load(Ordering::Relaxed)
+    }
+
+    /// Checks whether the asset server is watching its default source for changes, meaning hot reloading is active.
+    pub fn is_watching(&self) -> bool {
+        self.asset_server
+            .get_source(AssetSourceId::Default)
+            .is_ok_and(|source| {
+                source.event_receiver().is_some() || source.processed_event_receiver().is_some()
+            })
+    }
+
+    /// Inserts a lazy asset whose type is determined when it is loaded.
+    ///
+    /// It's read from the manager's asset source, and removing, renaming or splitting off its key applies to it too.
+    pub fn insert_untyped(&self, key: Key, path: &str) {
+        self.untyped.insert(key, path);
+    }
+
+    /// Gets an untyped handle to an asset inserted with `insert_untyped`, ensuring it's loaded if it was added lazily.
+    ///
+    /// Returns `None` until the asset server has finished loading the asset and determined its type.
+    pub fn get_untyped_resolved(&self, key: Key) -> Option<UntypedHandle> {
+        self.untyped.get_untyped(key)
+    }
+
+    /// Gets a typed handle to an asset inserted with `insert_untyped`, ensuring it's loaded if it was added lazily.
+    ///
+    /// Returns `None` if the asset hasn't finished loading or isn't a `T`.
+    pub fn get_as<T>(&self, key: Key) -> Option<Handle<T>>
+    where
+        T: bevy::asset::Asset,
+    {
+        self.untyped.get_typed(key)
+    }
+
+    /// Loads multiple assets, returning strong handles to them.
+    fn load_strong(&self, keys: &[Key]) -> Vec<(Key, Handle<Asset>)> {
+        let mut lock = self.assets.write().unwrap();
+        let handles = keys
+            .iter()
+            .filter_map(|key| {
+                let asset = lock.get_mut(key)?;
+                if let AssetHandle::Lazy(path) = asset {
+                    *asset = AssetHandle::Loaded {
+                        handle: self.promote(key.clone(), path),
+                        path: std::mem::take(path),
+                    };
+                }
+
+                match asset {
+                    AssetHandle::Loaded { handle, .. } if handle.is_strong() => {
+                        Some((key.clone(), handle.clone()))
+                    }
+                    asset => Some((key.clone(), self.load_path(asset.path()))),
+                }
+            })
+            .collect();
+        drop(lock);
+
+        self.fire_promoted();
+        handles
+    }
+
+    /// Gets a handle to an asset, promoting it if it was added lazily.
+    fn resolve(
+        &self,
+        key: Key,
+        asset: &mut AssetHandle<Asset>,
+        strength: Strength,
+    ) -> Handle<Asset> {
+        match asset {
+            AssetHandle::Lazy(path) => {
+                self.accessed
+                    .lock()
+                    .unwrap()
+                    .insert(key.clone(), self.now());
+                let handle = self.promote(key, path);
+                let resolved = match strength {
+                    Strength::Weak => handle.clone_weak(),
+                    Strength::Strong => handle.clone(),
+                };
+                *asset = AssetHandle::Loaded {
+                    path: std::mem::take(path),
+                    handle,
+                };
+
+                resolved
+            }
+            AssetHandle::Loaded { .. } => self.resolve_loaded(key, asset, strength),
+        }
+    }
+
+    /// Gets a handle of the given strength to an asset which is already loaded, recording its access.
+    ///
+    /// This only needs the assets read lock, unlike `resolve`.
+    fn resolve_loaded(
+        &self,
+        key: Key,
+        asset: &AssetHandle<Asset>,
+        strength: Strength,
+    ) -> Handle<Asset> {
+        self.accessed.lock().unwrap().insert(key, self.now());
+
+        // Only count a hit when no load has to be issued, which `load_path` counts instead
+        let handle = match asset {
+            AssetHandle::Loaded { handle, .. } if strength == Strength::Weak => handle.clone_weak(),
+            AssetHandle::Loaded { handle, .. } if handle.is_strong() => handle.clone(),
+            _ => return self.load_path(asset.path()),
+        };
+        #[cfg(feature = "metrics")]
+        self.counters.hits.fetch_add(1, Ordering::Relaxed);
+
+        handle
+    }
+
+    /// Gets a handle of the given strength to an asset like `resolve`, taking the assets write lock only if it has to be promoted.
+    fn resolve_shared(
+        &self,
+        key: Key,
+        strength: Strength,
+    ) -> Result<Option<Handle<Asset>>, AssetManagerError> {
+        let lock = self
+            .assets
+            .read()
+            .map_err(|_| AssetManagerError::LockPoisoned)?;
+        match lock.get(&key) {
+            None => {
+                if let Some(strict) = self.strict {
+                    strict(&key);
+                }
+
+                return Ok(None);
+            }
+            Some(asset) if asset.is_loaded() => {
+                return Ok(Some(self.resolve_loaded(key, asset, strength)))
+            }
+            Some(_) => drop(lock),
+        }
+
+        // The asset may have been promoted or removed between dropping the read lock and taking the write lock
+        let handle = self
+            .assets
+            .write()
+            .map_err(|_| AssetManagerError::LockPoisoned)?
+            .get_mut(&key)
+            .map(|asset| self.resolve(key, asset, strength));
+
+        self.fire_promoted();
+        Ok(handle)
+    }
+
+    /// Loads a lazy asset's path, queueing the promote hook to be fired by `fire_promoted`.
+    fn promote(&self, key: Key, path: &str) -> Handle<Asset> {
+        self.start_load_timer(key.clone());
+        let handle = self.load_path(path);
+        if self.on_promote.read().unwrap().is_some() {
+            self.promoted.lock().unwrap().push((key, path.to_owned()));
+        }
+
+        handle
+    }
+
+    /// Fires the promote hook for every queued promotion.
+    ///
+    /// This must be called after the assets lock is released, so hooks may safely use the manager.
+    fn fire_promoted(&self) {
+        let promoted = std::mem::take(&mut *self.promoted.lock().unwrap());
+        if promoted.is_empty() {
+            return;
+        }
+
+        if let Some(on_promote) = self.on_promote.read().unwrap().as_ref() {
+            promoted
+                .iter()
+                .for_each(|(key, path)| on_promote(key.clone(), path));
+        }
+    }
+
+    /// Loads an asset from the asset server, reusing the handle cache's handle if one is bound.
+    fn load_path(&self, path: &str) -> Handle<Asset> {
+        self.load_path_from(&self.source(), path)
+    }
+
+    /// Loads an asset from an asset source like `load_path`, unless the path names its own source.
+    fn load_path_from(&self, source: &AssetSourceId, path: &str) -> Handle<Asset> {
+        let path = compose_source_path(source, path);
+        #[cfg(feature = "metrics")]
+        self.counters.loads.fetch_add(1, Ordering::Relaxed);
+
+        match self.handle_cache.read().unwrap().as_ref() {
+            Some(handle_cache) => handle_cache.get_or_load(&self.asset_server, &path),
+            None => self.asset_server.load(path),
+        }
+    }
+
+    /// Reloads a loaded asset in place, or replaces its handle if its asset source changed since it was loaded.
+    fn reload_loaded(&self, asset: &mut AssetHandle<Asset>) {
+        if let AssetHandle::Loaded { path, handle } = asset {
+            let source_path = self.source_path(path);
+            match handle.path() {
+                Some(current) if *current == bevy::asset::AssetPath::parse(&source_path) => {
+                    self.asset_server.reload(source_path)
+                }
+                _ => *handle = self.load_path(path),
+            }
+        }
+    }
+
+    /// Removes everything recorded about assets besides their entries, such as their sizes, styles and tags.
+    ///
+    /// This must be called while holding the assets lock, after the entries are removed.
+    fn forget(&self, keys: &[Key]) {
+        let mut sizes = self.sizes.write().unwrap();
+        let mut styles = self.styles.write().unwrap();
+        let mut accessed = self.accessed.lock().unwrap();
+        let mut load_times = self.load_times.lock().unwrap();
+        let mut scopes = self.scopes.write().unwrap();
+        let mut tags = self.tags.write().unwrap();
+
+        keys.iter().for_each(|key| {
+            sizes.remove(key);
+            styles.remove(key);
+            accessed.remove(key);
+            load_times.remove(key);
+            scopes.remove(key);
+            tags.values_mut().for_each(|tagged| {
+                tagged.remove(key);
+            });
+        });
+        tags.retain(|_, tagged| !tagged.is_empty());
+    }
+
+    /// Shrinks the assets map to fit if auto shrinking is enabled and it's less than a quarter full.
+    fn shrink_if_sparse(&self, assets: &mut HashMap<Key, AssetHandle<Asset>>) {
+        if self.auto_shrink.load(Ordering::Relaxed) && assets.len() * 4 < assets.capacity() {
+            assets.shrink_to_fit();
+        }
+    }
+
+    /// Records the instant an asset started loading, for `load_durations`.
+    fn start_load_timer(&self, key: Key) {
+        self.load_times
+            .lock()
+            .unwrap()
+            .insert(key, (self.now(), None));
+    }
+
+    /// Reads the manager's clock.
+    fn now(&self) -> Instant {
+        (self.clock.read().unwrap())()
+    }
+
+    /// Composes a path with the current asset source, unless it names its own source.
+    fn source_path(&self, path: &str) -> String {
+        compose_source_path(&self.source.read().unwrap(), path)
+    }
+}
+
+impl<Key, Asset> Debug for AssetManager<Key, Asset>
+where
+    Key: PartialEq + Eq + Hash + Debug,
+    Asset: bevy::asset::Asset,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(
+                self.assets
+                    .read()
+                    .unwrap()
+                    .iter()
+                    .map(|(key, asset)| (key, EntryPath(asset))),
+            )
+            .finish()
+    }
+}
+
+impl<Key, Asset> FromWorld for AssetManager<Key, Asset>
+where
+    Key: PartialEq + Eq + Hash + Clone,
+    Asset: bevy::asset::Asset,
+{
+    /// Creates an empty `AssetManager` bound to the world's `AssetServer`.
+    fn from_world(world: &mut World) -> Self {
+        Self::new(world.resource::<AssetServer>().clone())
+    }
+}
+
+/// Builder accumulating lazy and loaded assets for an `AssetManager`.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::{
+///     asset::{Asset, AssetPlugin},
+///     prelude::{App, AssetApp, AssetServer, MinimalPlugins},
+///     reflect::TypePath,
+/// };
+/// use bevy_asset_manager::AssetManagerBuilder;
+///
+/// #[derive(Asset, TypePath)]
+/// struct AudioSource;
+///
+/// #[derive(Clone, PartialEq, Eq, Hash)]
+/// enum Audio {
+///    EngineOn,
+///    EngineStall,
+/// }
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+///     .init_asset::<AudioSource>();
+/// let asset_server = app.world.resource::<AssetServer>();
+///
+/// let empty_manager = AssetManagerBuilder::<Audio, AudioSource>::new(asset_server.clone()).build();
+/// assert!(empty_manager.is_empty());
+///
+/// // Entries can be added across several code paths before building
+/// let stalls = true;
+/// let mut builder = AssetManagerBuilder::<Audio, AudioSource>::new(asset_server.clone())
+///     .loaded(Audio::EngineOn, "sound/engine-on.ogg");
+/// if stalls {
+///     builder = builder.lazy(Audio::EngineStall, "sound/engine-stall.ogg");
+/// }
+///
+/// let audio_manager = builder.build();
+/// audio_manager.for_each(|key, asset| assert_eq!(asset.is_loaded(), *key == Audio::EngineOn));
+/// ```
+pub struct AssetManagerBuilder<Key, Asset>
+where
+    Key: PartialEq + Eq + Hash + Clone,
+    Asset: bevy::asset::Asset,
+{
+    asset_server: AssetServer,
+    lazy: Vec<(Key, String)>,
+    loaded: Vec<(Key, String)>,
+    _asset: PhantomData<fn() -> Asset>,
+}
+
+impl<Key, Asset> AssetManagerBuilder<Key, Asset>
+where
+    Key: PartialEq + Eq + Hash + Clone,
+    Asset: bevy::asset::Asset,
+{
+    /// Creates a new builder with no assets.
+    pub fn new(asset_server: AssetServer) -> Self {
+        Self {
+            asset_server,
+            lazy: Vec::new(),
+            loaded: Vec::new(),
+            _asset: PhantomData,
+        }
+    }
+
+    /// Adds an asset to be loaded when it's first requested.
+    pub fn lazy(mut self, key: Key, path: &str) -> Self {
+        self.lazy.push((key, path.to_owned()));
+        self
+    }
+
+    /// Adds an asset to be loaded when the manager is built.
+    pub fn loaded(mut self, key: Key, path: &str) -> Self {
+        self.loaded.push((key, path.to_owned()));
+        self
+    }
+
+    /// Builds the `AssetManager`, loading the loaded assets.
+    ///
+    /// Loaded assets are inserted after lazy ones, so a key added both ways ends up loaded.
+    pub fn build(self) -> AssetManager<Key, Asset> {
+        let asset_manager =
+            AssetManager::with_capacity(self.asset_server, self.lazy.len() + self.loaded.len());
+        asset_manager.insert_many(&borrow_pairs(&self.lazy));
+        asset_manager.insert_many_loaded(&borrow_pairs(&self.loaded));
+
+        asset_manager
+    }
+}
+
+/// Borrows the paths of owned key and path pairs.
+fn borrow_pairs<Key: Clone>(pairs: &[(Key, String)]) -> Vec<(Key, &str)> {
+    pairs
+        .iter()
+        .map(|(key, path)| (key.clone(), path.as_str()))
+        .collect()
+}
+
+/// System which trickle loads lazy assets within the frame's `LoadBudget`.
+pub fn asset_manager_budget_load<Key, Asset>(
+    budget: Res<LoadBudget>,
+    asset_manager: Res<AssetManager<Key, Asset>>,
+) where
+    Key: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    Asset: bevy::asset::Asset,
+{
+    asset_manager.load_within(budget.0);
+}
+
+/// Run condition which is true while the asset manager's asset server is watching for changes.
+///
+/// Hot reloading systems, like `asset_manager_hot_reload`, should run with this condition so they are inert when watching is disabled.
+pub fn asset_manager_watching<Key, Asset>(asset_manager: Res<AssetManager<Key, Asset>>) -> bool
+where
+    Key: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    Asset: bevy::asset::Asset,
+{
+    asset_manager.is_watching()
+}
+
+/// System which sends an `AssetModified` event for every loaded asset Bevy reports as modified.
+///
+/// `add_asset_manager_events` runs it with the `asset_manager_watching` condition, so it does no work unless hot reloading is active.
+pub fn asset_manager_hot_reload<Key, Asset>(
+    mut events: EventReader<AssetEvent<Asset>>,
+    asset_manager: Res<AssetManager<Key, Asset>>,
+    mut modified: EventWriter<AssetModified<Key, Asset>>,
+) where
+    Key: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    Asset: bevy::asset::Asset,
+{
+    let ids: HashSet<AssetId<Asset>> = events
+        .read()
+        .filter_map(|event| match event {
+            AssetEvent::Modified { id } => Some(*id),
+            _ => None,
+        })
+        .collect();
+    if ids.is_empty() {
+        return;
+    }
+
+    asset_manager.for_each(|key, asset| {
+        if let Some(id) = asset.handle().map(Handle::id).filter(|id| ids.contains(id)) {
+            modified.send(AssetModified {
+                key: key.clone(),
+                id,
+            });
+        }
+    });
+}
+
+/// System which loads every asset inserted with `insert_preload`, registered by `insert_asset_manager` to run once in `PostStartup`.
+pub fn asset_manager_preload<Key, Asset>(asset_manager: Res<AssetManager<Key, Asset>>)
+where
+    Key: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    Asset: bevy::asset::Asset,
+{
+    asset_manager.load_preload();
+}
+
+/// System which reverts loaded assets back to lazy assets once Bevy removes them.
+///
+/// Bevy removes an asset when no strong handles to it remain, so this only affects assets the manager holds weakly,
+/// which are those loaded before the last call to `unpin_all`.
+pub fn asset_manager_trim_unused<Key, Asset>(
+    mut events: EventReader<AssetEvent<Asset>>,
+    asset_manager: Res<AssetManager<Key, Asset>>,
+) where
+    Key: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    Asset: bevy::asset::Asset,
+{
+    asset_manager.trim_unused(events.read().filter_map(|event| match event {
+        AssetEvent::Removed { id } => Some(*id),
+        _ => None,
+    }));
+}
+
+/// System which sends `AssetStateChanged`, `AssetLoaded` and `AssetFailed` events as the load states of loaded assets change,
+/// and records load completions for `load_durations`.
+pub fn asset_manager_events<Key, Asset>(
+    mut states: Local<HashMap<Key, LoadState>>,
+    asset_manager: Res<AssetManager<Key, Asset>>,
+    mut changed: EventWriter<AssetStateChanged<Key, Asset>>,
+    mut loaded: EventWriter<AssetLoaded<Key, Asset>>,
+    mut failed: EventWriter<AssetFailed<Key, Asset>>,
+) where
+    Key: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    Asset: bevy::asset::Asset,
+{
+    let mut current = HashMap::with_capacity(states.len());
+    asset_manager.for_each(|key, asset| {
+        let Some(handle) = asset.handle() else {
+            return;
+        };
+        let Some(state) = asset_manager.asset_server.get_load_state(handle.id()) else {
+            return;
+        };
+
+        current.insert(key.clone(), state);
+        if states.get(key) == Some(&state) {
+            return;
+        }
+
+        let id = handle.id();
+        changed.send(AssetStateChanged {
+            key: key.clone(),
+            id,
+            state,
+        });
+        match state {
+            LoadState::Loaded => loaded.send(AssetLoaded {
+                key: key.clone(),
+                id,
+            }),
+            LoadState::Failed => failed.send(AssetFailed {
+                key: key.clone(),
+                id,
+            }),
+            _ => {}
+        }
+    });
+
+    *states = current;
+    asset_manager.record_load_completions();
+}
+
+/// System which sends `AllAssetsLoaded` once every loaded asset has finished loading, ignoring lazy assets which were never requested.
+///
+/// The event is sent at most once. Register it with the event and gate follow-up systems on it:
+///
+/// ```ignore
+/// app.add_event::<AllAssetsLoaded<Key, Image>>()
+///     .add_systems(Update, check_all_loaded::<Key, Image>)
+///     .add_systems(Update, enter_game.run_if(on_event::<AllAssetsLoaded<Key, Image>>()));
+/// ```
+pub fn check_all_loaded<Key, Asset>(
+    mut sent: Local<bool>,
+    asset_manager: Res<AssetManager<Key, Asset>>,
+    mut all_loaded: EventWriter<AllAssetsLoaded<Key, Asset>>,
+) where
+    Key: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    Asset: bevy::asset::Asset,
+{
+    if *sent {
+        return;
+    }
+
+    let mut loaded = true;
+    asset_manager.for_each(|_, asset| {
+        if let Some(handle) = asset.handle() {
+            loaded &=
+                asset_manager.asset_server.get_load_state(handle.id()) == Some(LoadState::Loaded);
+        }
+    });
+
+    if loaded {
+        all_loaded.send_default();
+        *sent = true;
+    }
+}
+
+/// Creates a system which removes every asset owned by `state`, as inserted by `insert_state_scoped`.
+///
+/// The system should be added to `OnExit(state)`, as `add_state_scoped_assets` does.
+pub fn asset_manager_state_cleanup<Key, Asset, S>(
+    state: S,
+) -> impl FnMut(Res<AssetManager<Key, Asset>>)
+where
+    Key: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    Asset: bevy::asset::Asset,
+    S: States,
+{
+    move |asset_manager| {
+        asset_manager.remove_state_scoped(&state);
+    }
+}
+
+/// System which delivers load state transitions to the receivers returned by `observe_key`.
+pub fn asset_manager_notify_observers<Key, Asset>(asset_manager: Res<AssetManager<Key, Asset>>)
+where
+    Key: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    Asset: bevy::asset::Asset,
+{
+    asset_manager.notify_observers();
+}
+
+/// System which rebuilds an `AssetKeyIndex` from its asset manager's loaded assets.
+pub fn asset_manager_key_index<Key, Asset>(
+    asset_manager: Res<AssetManager<Key, Asset>>,
+    mut index: ResMut<AssetKeyIndex<Key, Asset>>,
+) where
+    Key: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    Asset: bevy::asset::Asset,
+{
+    index.keys.clear();
+    asset_manager.for_each(|key, asset| {
+        if let Some(handle) = asset.handle() {
+            index.keys.insert(handle.id(), key.clone());
+        }
+    });
+}
+
+/// Binds an asset manager to the world's `HandleCache`, inserting the cache if it doesn't exist yet,
+/// unless the manager is already bound to a cache.
+fn bind_shared_handle_cache<Key, Asset>(world: &mut World, asset_manager: &AssetManager<Key, Asset>)
+where
+    Key: PartialEq + Eq + Hash + Clone,
+    Asset: bevy::asset::Asset,
+{
+    let mut handle_cache = asset_manager.handle_cache.write().unwrap();
+    if handle_cache.is_none() {
+        *handle_cache = Some(world.get_resource_or_insert_with(HandleCache::new).clone());
+    }
+}
+
+/// Marker resource recording that the key index and preload systems of an `AssetManager<Key, Asset>` were added to an app.
+#[derive(Resource)]
+struct AssetManagerSystems<Key, Asset>(PhantomData<fn() -> (Key, Asset)>);
+
+/// Marker resource recording that the event systems of an `AssetManager<Key, Asset>` were added to an app.
+#[derive(Resource)]
+struct AssetManagerEventSystems<Key, Asset>(PhantomData<fn() -> (Key, Asset)>);
+
+/// Adds the `asset_manager_key_index` and `asset_manager_preload` systems of an asset manager to an app, unless they were already added.
+fn add_asset_manager_systems<Key, Asset>(app: &mut App)
+where
+    Key: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    Asset: bevy::asset::Asset,
+{
+    if app
+        .world
+        .contains_resource::<AssetManagerSystems<Key, Asset>>()
+    {
+        return;
+    }
+
+    app.insert_resource(AssetManagerSystems::<Key, Asset>(PhantomData))
+        .init_resource::<AssetKeyIndex<Key, Asset>>()
+        .add_systems(
+            PreUpdate,
+            asset_manager_key_index::<Key, Asset>
+                .run_if(resource_exists::<AssetManager<Key, Asset>>()),
+        )
+        .add_systems(
+            PostStartup,
+            asset_manager_preload::<Key, Asset>
+                .run_if(resource_exists::<AssetManager<Key, Asset>>()),
+        );
+}
+
+/// Extension trait wiring asset managers into an `App`.
+pub trait AssetManagerAppExt {
+    /// Inserts an asset manager as a resource, along with an `AssetKeyIndex` kept in sync by the `asset_manager_key_index` system
+    /// and the `asset_manager_preload` system loading its preload assets.
+    ///
+    /// The key index system runs in `PreUpdate`, and the preload system once in `PostStartup`, so assets inserted with
+    /// `insert_preload` during `Startup` are loaded too. Unless the manager is already bound to a `HandleCache`, it's bound to the app's.
+    /// The systems are only added once per `Key` and `Asset` pair, so replacing the manager doesn't add them again.
+    fn insert_asset_manager<Key, Asset>(
+        &mut self,
+        asset_manager: AssetManager<Key, Asset>,
+    ) -> &mut Self
+    where
+        Key: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+        Asset: bevy::asset::Asset;
+
+    /// Inserts an empty asset manager bound to the app's `AssetServer` like `insert_asset_manager`, unless one already exists,
+    /// and registers its events like `add_asset_manager_events`.
+    ///
+    /// The systems are registered even if the manager already exists, but only once, so this may be called by several plugins.
+    /// Bevy's `AssetPlugin` must be added first.
+    fn init_asset_manager<Key, Asset>(&mut self) -> &mut Self
+    where
+        Key: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+        Asset: bevy::asset::Asset;
+
+    /// Registers every asset manager event type for a `Key` and `Asset` pair, along with the `asset_manager_events` and
+    /// `asset_manager_hot_reload` systems sending them and the `asset_manager_notify_observers` system.
+    ///
+    /// The systems run in `PreUpdate` while an `AssetManager<Key, Asset>` resource exists, and `asset_manager_hot_reload`
+    /// only while its asset server is watching for changes. Calling this again for the same pair does nothing.
+    fn add_asset_manager_events<Key, Asset>(&mut self) -> &mut Self
+    where
+        Key: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+        Asset: bevy::asset::Asset;
+
+    /// Adds an `asset_manager_state_cleanup` system to `OnExit(state)`, so assets inserted with `insert_state_scoped` for
+    /// `state` are removed once it exits.
+    ///
+    /// This should be called once for each state owning assets. The system only runs while an `AssetManager<Key, Asset>` resource exists.
+    fn add_state_scoped_assets<Key, Asset, S>(&mut self, state: S) -> &mut Self
+    where
+        Key: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+        Asset: bevy::asset::Asset,
+        S: States;
+}
+
+impl AssetManagerAppExt for App {
+    fn insert_asset_manager<Key, Asset>(
+        &mut self,
+        asset_manager: AssetManager<Key, Asset>,
+    ) -> &mut Self
+    where
+        Key: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+        Asset: bevy::asset::Asset,
+    {
+        bind_shared_handle_cache(&mut self.world, &asset_manager);
+        self.insert_resource(asset_manager);
+        add_asset_manager_systems::<Key, Asset>(self);
+
+        self
+    }
+
+    fn init_asset_manager<Key, Asset>(&mut self) -> &mut Self
+    where
+        Key: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+        Asset: bevy::asset::Asset,
+    {
+        match self.world.contains_resource::<AssetManager<Key, Asset>>() {
+            true => {
+                self.world
+                    .resource_scope(|world, asset_manager: Mut<AssetManager<Key, Asset>>| {
+                        bind_shared_handle_cache(world, &asset_manager)
+                    });
+                add_asset_manager_systems::<Key, Asset>(self);
+            }
+            false => {
+                let asset_manager = AssetManager::<Key, Asset>::from_world(&mut self.world);
+                self.insert_asset_manager(asset_manager);
+            }
+        }
+
+        self.add_asset_manager_events::<Key, Asset>()
+    }
+
+    fn add_asset_manager_events<Key, Asset>(&mut self) -> &mut Self
+    where
+        Key: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+        Asset: bevy::asset::Asset,
+    {
+        if self
+            .world
+            .contains_resource::<AssetManagerEventSystems<Key, Asset>>()
+        {
+            return self;
+        }
+
+        self.insert_resource(AssetManagerEventSystems::<Key, Asset>(PhantomData))
+            .add_event::<AssetStateChanged<Key, Asset>>()
+            .add_event::<AssetLoaded<Key, Asset>>()
+            .add_event::<AssetFailed<Key, Asset>>()
+            .add_event::<AssetModified<Key, Asset>>()
+            .add_systems(
+                PreUpdate,
+                (
+                    asset_manager_events::<Key, Asset>,
+                    asset_manager_notify_observers::<Key, Asset>,
+                )
+                    .run_if(resource_exists::<AssetManager<Key, Asset>>()),
+            )
+            .add_systems(
+                PreUpdate,
+                asset_manager_hot_reload::<Key, Asset>.run_if(
+                    resource_exists::<AssetManager<Key, Asset>>()
+                        .and_then(asset_manager_watching::<Key, Asset>),
+                ),
+            )
+    }
+
+    fn add_state_scoped_assets<Key, Asset, S>(&mut self, state: S) -> &mut Self
+    where
+        Key: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+        Asset: bevy::asset::Asset,
+        S: States,
+    {
+        self.add_systems(
+            OnExit(state.clone()),
+            asset_manager_state_cleanup::<Key, Asset, S>(state)
+                .run_if(resource_exists::<AssetManager<Key, Asset>>()),
+        )
+    }
+}
+
+/// System which resets `LoadProgress` at the start of each frame, before each manager's `asset_manager_progress` adds to it.
+pub fn asset_manager_reset_progress(mut progress: ResMut<LoadProgress>) {
+    *progress = LoadProgress::default();
+}
+
+/// System which adds an asset manager's requested and loaded assets to `LoadProgress`.
+pub fn asset_manager_progress<Key, Asset>(
+    asset_manager: Res<AssetManager<Key, Asset>>,
+    mut progress: ResMut<LoadProgress>,
+) where
+    Key: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    Asset: bevy::asset::Asset,
+{
+    let counts = asset_manager.state_counts();
+    progress.loaded += counts.loaded;
+    progress.total += counts.loading + counts.loaded + counts.failed;
+}
+
+/// Plugin inserting an `AssetManager<Key, Asset>` built from the app's `AssetServer` with `insert_asset_manager`,
+/// and tracking its progress in `LoadProgress`.
+///
+/// The manager's preload assets are loaded in `PostStartup`, and it's bound to the app's `HandleCache` unless `build` binds it to another.
+///
+/// The plugin must be added after Bevy's `AssetPlugin`.
+pub struct AssetManagerPlugin<Key, Asset>
+where
+    Key: PartialEq + Eq + Hash,
+    Asset: bevy::asset::Asset,
+{
+    build: Box<dyn Fn(AssetServer) -> AssetManager<Key, Asset> + Send + Sync>,
+}
+
+impl<Key, Asset> AssetManagerPlugin<Key, Asset>
+where
+    Key: PartialEq + Eq + Hash,
+    Asset: bevy::asset::Asset,
+{
+    /// Creates a plugin which builds its asset manager with `build`.
+    pub fn new(
+        build: impl Fn(AssetServer) -> AssetManager<Key, Asset> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            build: Box::new(build),
+        }
+    }
+}
+
+impl<Key, Asset> Default for AssetManagerPlugin<Key, Asset>
+where
+    Key: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    Asset: bevy::asset::Asset,
+{
+    /// Creates a plugin which inserts an empty asset manager.
+    fn default() -> Self {
+        Self::new(AssetManager::new)
+    }
+}
+
+impl<Key, Asset> Plugin for AssetManagerPlugin<Key, Asset>
+where
+    Key: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    Asset: bevy::asset::Asset,
+{
+    fn build(&self, app: &mut App) {
+        let asset_server = app.world.resource::<AssetServer>().clone();
+        if !app.world.contains_resource::<LoadProgress>() {
+            app.init_resource::<LoadProgress>()
+                .add_systems(First, asset_manager_reset_progress);
+        }
+
+        app.insert_asset_manager((self.build)(asset_server))
+            .add_systems(PreUpdate, asset_manager_progress::<Key, Asset>);
     }
 }
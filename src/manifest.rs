@@ -0,0 +1,382 @@
+use crate::{AssetHandle, AssetManager, LoadStyle};
+use bevy::prelude::AssetServer;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    env,
+    error::Error,
+    fmt::{self, Display, Formatter},
+    fs,
+    hash::Hash,
+    io::{self, ErrorKind},
+    path::Path,
+    str::FromStr,
+};
+
+/// A single key, path and load style entry of an asset manifest.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ManifestEntry<Key> {
+    /// The key the asset is registered under.
+    pub key: Key,
+    /// The path the asset is loaded from.
+    pub path: String,
+    /// Whether the asset is loaded lazily or eagerly.
+    pub style: LoadStyle,
+}
+
+/// The key, path and load style of every asset of an `AssetManager`, serialized the same way as a manifest file.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AssetManifest<Key> {
+    /// The manifest's entries.
+    pub entries: Vec<ManifestEntry<Key>>,
+}
+
+/// Errors returned when reading a manifest file.
+#[derive(Debug)]
+pub enum ManifestError {
+    /// The environment variable naming the manifest file is unset or not unicode.
+    MissingVar(String),
+    /// The manifest file couldn't be read.
+    Io(io::Error),
+    /// The manifest file's extension is neither `ron` nor `json`.
+    UnsupportedExtension,
+    /// The manifest file isn't valid RON.
+    Ron(ron::error::SpannedError),
+    /// The manifest file isn't valid JSON.
+    Json(serde_json::Error),
+    /// A key in the manifest couldn't be parsed.
+    InvalidKey(String),
+}
+
+impl Display for ManifestError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ManifestError::MissingVar(var) => {
+                write!(f, "environment variable `{var}` is not set")
+            }
+            ManifestError::Io(error) => write!(f, "failed to read manifest: {error}"),
+            ManifestError::UnsupportedExtension => {
+                write!(f, "manifest path must have a `ron` or `json` extension")
+            }
+            ManifestError::Ron(error) => write!(f, "malformed RON manifest: {error}"),
+            ManifestError::Json(error) => write!(f, "malformed JSON manifest: {error}"),
+            ManifestError::InvalidKey(key) => write!(f, "invalid key `{key}` in manifest"),
+        }
+    }
+}
+
+impl Error for ManifestError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ManifestError::Io(error) => Some(error),
+            ManifestError::Ron(error) => Some(error),
+            ManifestError::Json(error) => Some(error),
+            ManifestError::MissingVar(_)
+            | ManifestError::UnsupportedExtension
+            | ManifestError::InvalidKey(_) => None,
+        }
+    }
+}
+
+impl<Key, Asset> AssetManager<Key, Asset>
+where
+    Key: PartialEq + Eq + Hash + Clone,
+    Asset: bevy::asset::Asset,
+{
+    /// Creates an `AssetManager` from a RON manifest of `ManifestEntry`s.
+    pub fn from_ron(
+        asset_server: AssetServer,
+        manifest: &str,
+    ) -> Result<Self, ron::error::SpannedError>
+    where
+        Key: DeserializeOwned,
+    {
+        let entries = ron::from_str(manifest)?;
+
+        Ok(Self::from_entries(asset_server, entries))
+    }
+
+    /// Creates an `AssetManager` from a JSON manifest of `ManifestEntry`s.
+    pub fn from_json(asset_server: AssetServer, manifest: &str) -> serde_json::Result<Self>
+    where
+        Key: DeserializeOwned,
+    {
+        let entries = serde_json::from_str(manifest)?;
+
+        Ok(Self::from_entries(asset_server, entries))
+    }
+
+    /// Creates an `AssetManager` from a RON manifest of `ManifestEntry`s keyed by strings, parsing each key with `FromStr`.
+    pub fn from_manifest_str(
+        asset_server: AssetServer,
+        manifest: &str,
+    ) -> Result<Self, ManifestError>
+    where
+        Key: FromStr,
+    {
+        let entries: Vec<ManifestEntry<String>> =
+            ron::from_str(manifest).map_err(ManifestError::Ron)?;
+        let entries = entries
+            .into_iter()
+            .map(|entry| {
+                Ok(ManifestEntry {
+                    key: entry
+                        .key
+                        .parse()
+                        .map_err(|_| ManifestError::InvalidKey(entry.key))?,
+                    path: entry.path,
+                    style: entry.style,
+                })
+            })
+            .collect::<Result<_, ManifestError>>()?;
+
+        Ok(Self::from_entries(asset_server, entries))
+    }
+
+    /// Creates an `AssetManager` from a RON or JSON manifest file, chosen by its extension.
+    pub fn from_manifest_file(asset_server: AssetServer, path: &Path) -> Result<Self, ManifestError>
+    where
+        Key: DeserializeOwned,
+    {
+        let manifest = fs::read_to_string(path).map_err(ManifestError::Io)?;
+
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("ron") => Self::from_ron(asset_server, &manifest).map_err(ManifestError::Ron),
+            Some("json") => Self::from_json(asset_server, &manifest).map_err(ManifestError::Json),
+            _ => Err(ManifestError::UnsupportedExtension),
+        }
+    }
+
+    /// Creates an `AssetManager` from the RON or JSON manifest file named by the environment variable `var`.
+    pub fn from_env(asset_server: AssetServer, var: &str) -> Result<Self, ManifestError>
+    where
+        Key: DeserializeOwned,
+    {
+        let path = env::var(var).map_err(|_| ManifestError::MissingVar(var.to_owned()))?;
+
+        Self::from_manifest_file(asset_server, Path::new(&path))
+    }
+
+    /// Collects every registered key, path and load style into an `AssetManifest`.
+    ///
+    /// Each asset's style is the one it was inserted with, so lazy assets which have since been loaded are still exported as lazy.
+    /// Assets without a recorded style are exported as lazy or loaded depending on whether they're currently loaded.
+    pub fn to_manifest(&self) -> AssetManifest<Key> {
+        let lock = self.assets.read().unwrap();
+        let styles = self.styles.read().unwrap();
+        let entries = lock
+            .iter()
+            .map(|(key, asset)| ManifestEntry {
+                key: key.clone(),
+                path: asset.path().to_owned(),
+                style: styles.get(key).copied().unwrap_or(match asset {
+                    AssetHandle::Lazy(_) => LoadStyle::Lazy,
+                    AssetHandle::Loaded { .. } => LoadStyle::Loaded,
+                }),
+            })
+            .collect();
+
+        AssetManifest { entries }
+    }
+
+    /// Inserts every asset of a manifest with its load style, overwriting assets already registered under the same keys.
+    pub fn apply_manifest(&self, manifest: AssetManifest<Key>) {
+        manifest
+            .entries
+            .into_iter()
+            .for_each(|entry| self.insert_with_style(entry.key, &entry.path, entry.style));
+    }
+
+    /// Writes every registered key, path and load style to `path` as RON or JSON, chosen by its extension.
+    pub fn export_manifest(&self, path: &Path) -> io::Result<()>
+    where
+        Key: Serialize,
+    {
+        let entries = self.to_manifest().entries;
+
+        let contents = match path.extension().and_then(|extension| extension.to_str()) {
+            Some("ron") => ron::ser::to_string_pretty(&entries, Default::default())
+                .map_err(|error| io::Error::new(ErrorKind::InvalidData, error))?,
+            Some("json") => serde_json::to_string_pretty(&entries)?,
+            _ => {
+                return Err(io::Error::new(
+                    ErrorKind::InvalidInput,
+                    "manifest path must have a `ron` or `json` extension",
+                ))
+            }
+        };
+
+        fs::write(path, contents)
+    }
+
+    fn from_entries(asset_server: AssetServer, entries: Vec<ManifestEntry<Key>>) -> Self {
+        let asset_manager = Self::new(asset_server);
+        asset_manager.apply_manifest(AssetManifest { entries });
+
+        asset_manager
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ManifestError;
+    use crate::{
+        tests::{app, asset_server, Key, Text},
+        AssetManager, LoadStyle,
+    };
+    use std::{env, fs};
+
+    /// Gets every entry of a manager's manifest as a tuple, sorted by key.
+    fn sorted_entries(asset_manager: &AssetManager<Key, Text>) -> Vec<(Key, String, LoadStyle)> {
+        let mut entries: Vec<_> = asset_manager
+            .to_manifest()
+            .entries
+            .into_iter()
+            .map(|entry| (entry.key, entry.path, entry.style))
+            .collect();
+        entries.sort_by_key(|(key, _, _)| *key);
+
+        entries
+    }
+
+    #[test]
+    fn exported_ron_manifest_round_trips() {
+        let app = app();
+        let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+        asset_manager.insert(Key::A, "a.txt");
+        asset_manager.insert_loaded(Key::B, "b.txt");
+
+        let path = env::temp_dir().join(format!("asset-manifest-{}.ron", std::process::id()));
+        asset_manager.export_manifest(&path).unwrap();
+        let manifest = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        let imported = AssetManager::<Key, Text>::from_ron(asset_server(&app), &manifest).unwrap();
+        assert_eq!(sorted_entries(&imported), sorted_entries(&asset_manager));
+        assert_eq!(
+            sorted_entries(&imported),
+            vec![
+                (Key::A, "a.txt".to_owned(), LoadStyle::Lazy),
+                (Key::B, "b.txt".to_owned(), LoadStyle::Loaded),
+            ]
+        );
+    }
+
+    #[test]
+    fn json_manifest_round_trips_inserted_styles() {
+        let app = app();
+        let asset_manager = AssetManager::<u32, Text>::new(asset_server(&app));
+        asset_manager.insert(1, "a.txt");
+        asset_manager.insert_loaded(2, "b.txt");
+        asset_manager.insert_preload(3, "c.txt");
+        // Loading a lazy asset doesn't change the style it's exported with
+        asset_manager.get(1);
+
+        let manifest = serde_json::to_string(&asset_manager.to_manifest()).unwrap();
+        let imported = AssetManager::<u32, Text>::from_json(asset_server(&app), &manifest).unwrap();
+        let mut entries: Vec<_> = imported
+            .to_manifest()
+            .entries
+            .into_iter()
+            .map(|entry| (entry.key, entry.path, entry.style))
+            .collect();
+        entries.sort_by_key(|(key, _, _)| *key);
+
+        assert_eq!(
+            entries,
+            vec![
+                (1, "a.txt".to_owned(), LoadStyle::Lazy),
+                (2, "b.txt".to_owned(), LoadStyle::Loaded),
+                (3, "c.txt".to_owned(), LoadStyle::Preload),
+            ]
+        );
+    }
+
+    #[test]
+    fn from_env_reads_the_manifest_file_the_variable_names() {
+        let app = app();
+        let path = env::temp_dir().join(format!("asset-manifest-env-{}.ron", std::process::id()));
+        fs::write(
+            &path,
+            r#"[(key: A, path: "a.txt", style: Lazy), (key: B, path: "b.txt", style: Loaded)]"#,
+        )
+        .unwrap();
+        let var = format!("ASSET_MANIFEST_{}", std::process::id());
+        env::set_var(&var, &path);
+
+        let asset_manager = AssetManager::<Key, Text>::from_env(asset_server(&app), &var);
+        env::remove_var(&var);
+        fs::remove_file(&path).unwrap();
+
+        let asset_manager = asset_manager.unwrap();
+        assert_eq!(
+            sorted_entries(&asset_manager),
+            vec![
+                (Key::A, "a.txt".to_owned(), LoadStyle::Lazy),
+                (Key::B, "b.txt".to_owned(), LoadStyle::Loaded),
+            ]
+        );
+        assert!(asset_manager.peek(Key::B).is_some());
+    }
+
+    #[test]
+    fn from_env_fails_without_the_variable() {
+        let app = app();
+        let var = format!("ASSET_MANIFEST_UNSET_{}", std::process::id());
+
+        let error = AssetManager::<Key, Text>::from_env(asset_server(&app), &var).unwrap_err();
+        assert!(matches!(error, ManifestError::MissingVar(missing) if missing == var));
+    }
+
+    #[test]
+    fn manifest_files_need_a_ron_or_json_extension() {
+        let app = app();
+        let path = env::temp_dir().join(format!("asset-manifest-{}.toml", std::process::id()));
+        fs::write(&path, "[]").unwrap();
+
+        let error = AssetManager::<Key, Text>::from_manifest_file(asset_server(&app), &path);
+        fs::remove_file(&path).unwrap();
+        assert!(matches!(error, Err(ManifestError::UnsupportedExtension)));
+    }
+
+    #[test]
+    fn string_keyed_manifests_parse_each_key() {
+        let app = app();
+        let manifest = r#"[
+            (key: "1", path: "a.txt", style: Lazy),
+            (key: "2", path: "b.txt", style: Loaded),
+            (key: "3", path: "c.txt", style: Preload),
+        ]"#;
+
+        let asset_manager =
+            AssetManager::<u32, Text>::from_manifest_str(asset_server(&app), manifest).unwrap();
+        let mut entries: Vec<_> = asset_manager
+            .to_manifest()
+            .entries
+            .into_iter()
+            .map(|entry| (entry.key, entry.path, entry.style))
+            .collect();
+        entries.sort_by_key(|(key, _, _)| *key);
+
+        assert_eq!(
+            entries,
+            vec![
+                (1, "a.txt".to_owned(), LoadStyle::Lazy),
+                (2, "b.txt".to_owned(), LoadStyle::Loaded),
+                (3, "c.txt".to_owned(), LoadStyle::Preload),
+            ]
+        );
+        assert!(asset_manager.peek(2).is_some());
+    }
+
+    #[test]
+    fn string_keyed_manifests_reject_unparsable_keys() {
+        let app = app();
+        let manifest =
+            r#"[(key: "1", path: "a.txt", style: Lazy), (key: "two", path: "b.txt", style: Lazy)]"#;
+
+        let error =
+            AssetManager::<u32, Text>::from_manifest_str(asset_server(&app), manifest).unwrap_err();
+        assert!(matches!(error, ManifestError::InvalidKey(key) if key == "two"));
+    }
+}
@@ -0,0 +1,247 @@
+use crate::AssetManager;
+use bevy::{
+    app::{App, Plugin, Update},
+    diagnostic::{Diagnostic, DiagnosticId, Diagnostics, RegisterDiagnostic},
+    ecs::schedule::{common_conditions::resource_exists, IntoSystemConfigs},
+    prelude::Res,
+};
+use std::{
+    any::{type_name, TypeId},
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+/// Counters an `AssetManager` updates as it's used.
+#[derive(Default)]
+pub(crate) struct Counters {
+    pub(crate) loads: AtomicU64,
+    pub(crate) hits: AtomicU64,
+}
+
+/// A snapshot of an `AssetManager`'s counters.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AssetManagerMetrics {
+    /// How many loads the manager has issued to the asset server.
+    pub loads: u64,
+    /// How many gets resolved to an already loaded asset without issuing a load.
+    pub hits: u64,
+    /// How many loaded assets have failed to load.
+    pub failures: u64,
+}
+
+impl<Key, Asset> AssetManager<Key, Asset>
+where
+    Key: PartialEq + Eq + Hash + Clone,
+    Asset: bevy::asset::Asset,
+{
+    /// Takes a snapshot of the manager's counters.
+    pub fn metrics(&self) -> AssetManagerMetrics {
+        AssetManagerMetrics {
+            loads: self.counters.loads.load(Ordering::Relaxed),
+            hits: self.counters.hits.load(Ordering::Relaxed),
+            failures: self.failed_keys().len() as u64,
+        }
+    }
+}
+
+/// Plugin registering an `AssetManager<Key, Asset>`'s metrics as Bevy diagnostics.
+///
+/// Each `Key` and `Asset` pair has its own diagnostic ids, so the plugin may be added for several managers.
+pub struct AssetManagerDiagnosticsPlugin<Key, Asset>(PhantomData<fn() -> (Key, Asset)>);
+
+impl<Key, Asset> Default for AssetManagerDiagnosticsPlugin<Key, Asset> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<Key, Asset> AssetManagerDiagnosticsPlugin<Key, Asset>
+where
+    Key: 'static,
+    Asset: 'static,
+{
+    /// How many loads the manager has issued to the asset server.
+    pub fn loads() -> DiagnosticId {
+        Self::diagnostic_id("loads")
+    }
+
+    /// How many gets resolved to an already loaded asset without issuing a load.
+    pub fn hits() -> DiagnosticId {
+        Self::diagnostic_id("hits")
+    }
+
+    /// How many loaded assets have failed to load.
+    pub fn failures() -> DiagnosticId {
+        Self::diagnostic_id("failures")
+    }
+
+    /// Derives the id of a metric from its name and the manager's `Key` and `Asset` types.
+    fn diagnostic_id(metric: &str) -> DiagnosticId {
+        let half = |salt: u8| {
+            let mut hasher = DefaultHasher::new();
+            (salt, metric, TypeId::of::<Key>(), TypeId::of::<Asset>()).hash(&mut hasher);
+            hasher.finish() as u128
+        };
+
+        DiagnosticId::from_u128(half(0) << 64 | half(1))
+    }
+
+    /// Names a metric after the manager's `Key` and `Asset` types.
+    fn diagnostic_name(metric: &str) -> String {
+        format!(
+            "asset_manager_{metric}<{}, {}>",
+            type_name::<Key>(),
+            type_name::<Asset>()
+        )
+    }
+}
+
+impl<Key, Asset> Plugin for AssetManagerDiagnosticsPlugin<Key, Asset>
+where
+    Key: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    Asset: bevy::asset::Asset,
+{
+    fn build(&self, app: &mut App) {
+        app.register_diagnostic(Diagnostic::new(
+            Self::loads(),
+            Self::diagnostic_name("loads"),
+            20,
+        ))
+        .register_diagnostic(Diagnostic::new(
+            Self::hits(),
+            Self::diagnostic_name("hits"),
+            20,
+        ))
+        .register_diagnostic(Diagnostic::new(
+            Self::failures(),
+            Self::diagnostic_name("failures"),
+            20,
+        ))
+        .add_systems(
+            Update,
+            asset_manager_diagnostics::<Key, Asset>
+                .run_if(resource_exists::<AssetManager<Key, Asset>>()),
+        );
+    }
+}
+
+/// System which records an asset manager's metrics as diagnostics.
+pub fn asset_manager_diagnostics<Key, Asset>(
+    mut diagnostics: Diagnostics,
+    asset_manager: Res<AssetManager<Key, Asset>>,
+) where
+    Key: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    Asset: bevy::asset::Asset,
+{
+    let metrics = asset_manager.metrics();
+    diagnostics.add_measurement(AssetManagerDiagnosticsPlugin::<Key, Asset>::loads(), || {
+        metrics.loads as f64
+    });
+    diagnostics.add_measurement(AssetManagerDiagnosticsPlugin::<Key, Asset>::hits(), || {
+        metrics.hits as f64
+    });
+    diagnostics.add_measurement(
+        AssetManagerDiagnosticsPlugin::<Key, Asset>::failures(),
+        || metrics.failures as f64,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AssetManagerDiagnosticsPlugin;
+    use crate::{
+        tests::{app, asset_server, run_until, Key, Text},
+        AssetManager,
+    };
+    use bevy::{
+        asset::io::AssetSourceId,
+        diagnostic::{Diagnostic, DiagnosticId, DiagnosticsPlugin, DiagnosticsStore},
+        prelude::App,
+    };
+
+    /// Gets the latest value of a diagnostic.
+    fn measurement(app: &App, id: DiagnosticId) -> Option<f64> {
+        app.world
+            .resource::<DiagnosticsStore>()
+            .get(id)
+            .and_then(Diagnostic::value)
+    }
+
+    #[test]
+    fn diagnostics_count_each_get_as_either_a_load_or_a_hit() {
+        let mut app = app();
+        app.add_plugins((
+            DiagnosticsPlugin,
+            AssetManagerDiagnosticsPlugin::<Key, Text>::default(),
+        ));
+        let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+        asset_manager.insert(Key::A, "a.txt");
+        asset_manager.insert(Key::B, "missing.txt");
+
+        asset_manager.get(Key::A);
+        asset_manager.get(Key::A);
+        asset_manager.unpin_all();
+        // The stored handle is weak now, so a strong get has to issue a load
+        asset_manager.get_strong(Key::A);
+        asset_manager.load(Key::B);
+        app.insert_resource(asset_manager);
+
+        let failures = AssetManagerDiagnosticsPlugin::<Key, Text>::failures();
+        run_until(&mut app, |app| measurement(app, failures) == Some(1.0));
+        let loads = AssetManagerDiagnosticsPlugin::<Key, Text>::loads();
+        let hits = AssetManagerDiagnosticsPlugin::<Key, Text>::hits();
+        assert_eq!(measurement(&app, loads), Some(3.0));
+        assert_eq!(measurement(&app, hits), Some(1.0));
+    }
+
+    #[test]
+    fn get_from_counts_as_a_load() {
+        let app = app();
+        let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+        asset_manager.insert(Key::A, "a.txt");
+
+        asset_manager.get_from(Key::A, AssetSourceId::Default);
+        assert_eq!(asset_manager.metrics().loads, 1);
+        assert!(asset_manager.peek(Key::A).is_none());
+    }
+
+    #[test]
+    fn get_cached_counts_hits_without_loads() {
+        let app = app();
+        let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+        asset_manager.insert_loaded(Key::A, "a.txt");
+
+        asset_manager.get_cached(Key::A);
+        asset_manager.get_cached(Key::A);
+        assert_eq!(asset_manager.metrics().loads, 1);
+        assert_eq!(asset_manager.metrics().hits, 2);
+    }
+
+    #[test]
+    fn each_manager_type_records_its_own_diagnostics() {
+        let mut app = app();
+        app.add_plugins((
+            DiagnosticsPlugin,
+            AssetManagerDiagnosticsPlugin::<Key, Text>::default(),
+            AssetManagerDiagnosticsPlugin::<u32, Text>::default(),
+        ));
+        let keyed = AssetManager::<Key, Text>::new(asset_server(&app));
+        keyed.insert_loaded(Key::A, "a.txt");
+        let numbered = AssetManager::<u32, Text>::new(asset_server(&app));
+        numbered.insert_many_loaded(&[(1, "a.txt"), (2, "b.txt")]);
+        app.insert_resource(keyed).insert_resource(numbered);
+        app.update();
+
+        let keyed_loads = AssetManagerDiagnosticsPlugin::<Key, Text>::loads();
+        let numbered_loads = AssetManagerDiagnosticsPlugin::<u32, Text>::loads();
+        assert_ne!(keyed_loads, numbered_loads);
+        assert_ne!(
+            keyed_loads,
+            AssetManagerDiagnosticsPlugin::<Key, Text>::hits()
+        );
+        assert_eq!(measurement(&app, keyed_loads), Some(1.0));
+        assert_eq!(measurement(&app, numbered_loads), Some(2.0));
+    }
+}
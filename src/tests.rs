@@ -0,0 +1,1913 @@
+use crate::{
+    asset_manager_budget_load, asset_manager_hot_reload, asset_manager_trim_unused,
+    check_all_loaded, mixed_asset_manager, AllAssetsLoaded, AssetFailed, AssetHandle,
+    AssetKeyCount, AssetKeyIndex, AssetLoaded, AssetManager, AssetManagerAppExt, AssetManagerError,
+    AssetManagerPlugin, AssetModified, AssetPath, AssetStateChanged, HandleCache, LoadBudget,
+    LoadProgress, LoadStyle, StateCounts, Strength, UntypedAssetManager,
+};
+use bevy::{
+    asset::{
+        io::{file::FileAssetReader, AssetSource, AssetSourceId, Reader},
+        AssetLoader, AssetPlugin, AsyncReadExt, LoadContext, LoadState,
+        RecursiveDependencyLoadState,
+    },
+    ecs::system::CommandQueue,
+    prelude::{
+        App, Asset, AssetApp, AssetEvent, AssetServer, Assets, Commands, Events, Handle,
+        MinimalPlugins, Mut, NextState, Plugin, PostUpdate, Res, Startup, States, Update,
+    },
+    reflect::TypePath,
+    utils::{
+        hashbrown::{hash_map::Entry, HashMap, HashSet},
+        tracing::{
+            self,
+            field::Field,
+            span::{Attributes, Id, Record},
+            Level, Metadata, Subscriber,
+        },
+        BoxedFuture, Duration, Instant,
+    },
+};
+use futures_lite::future::{block_on, poll_once};
+use serde::{Deserialize, Serialize};
+use std::{
+    env,
+    fmt::Debug,
+    fs,
+    path::PathBuf,
+    process,
+    sync::{mpsc, Arc, Mutex},
+    thread,
+};
+
+/// Text asset loaded from `.txt` files, which load the file they name as a dependency if they start with `dep:`.
+#[derive(Asset, TypePath, Debug)]
+pub(crate) struct Text {
+    pub(crate) content: String,
+    #[dependency]
+    pub(crate) dependency: Option<Handle<Text>>,
+}
+
+/// Settings of `TextLoader`.
+#[derive(Default, Serialize, Deserialize)]
+pub(crate) struct TextSettings {
+    pub(crate) uppercase: bool,
+}
+
+#[derive(Default)]
+pub(crate) struct TextLoader;
+
+impl AssetLoader for TextLoader {
+    type Asset = Text;
+    type Settings = TextSettings;
+    type Error = std::io::Error;
+
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        settings: &'a TextSettings,
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<Text, std::io::Error>> {
+        Box::pin(async move {
+            let mut content = String::new();
+            reader.read_to_string(&mut content).await?;
+            let content = content.trim().to_owned();
+            let dependency = content
+                .strip_prefix("dep:")
+                .map(|path| load_context.load(path.to_owned()));
+
+            Ok(Text {
+                content: match settings.uppercase {
+                    true => content.to_uppercase(),
+                    false => content,
+                },
+                dependency,
+            })
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["txt"]
+    }
+}
+
+/// Keys of the files in the crate's `assets` folder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub(crate) enum Key {
+    A,
+    B,
+}
+
+impl AssetKeyCount for Key {
+    const COUNT: usize = 2;
+}
+
+impl AssetPath for Key {
+    fn asset_path(&self) -> String {
+        match self {
+            Key::A => String::from("a.txt"),
+            Key::B => String::from("b.txt"),
+        }
+    }
+}
+
+/// Binary asset loaded from `.bin` files, for tests mixing asset types.
+#[derive(Asset, TypePath, Debug)]
+pub(crate) struct Blob(pub(crate) Vec<u8>);
+
+#[derive(Default)]
+pub(crate) struct BlobLoader;
+
+impl AssetLoader for BlobLoader {
+    type Asset = Blob;
+    type Settings = ();
+    type Error = std::io::Error;
+
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        _settings: &'a (),
+        _load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<Blob, std::io::Error>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+
+            Ok(Blob(bytes))
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["bin"]
+    }
+}
+
+/// Subscriber recording the messages of error events, for tests asserting what's logged.
+#[derive(Clone, Default)]
+struct ErrorLog(Arc<Mutex<Vec<String>>>);
+
+impl Subscriber for ErrorLog {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        *metadata.level() == Level::ERROR
+    }
+
+    fn new_span(&self, _span: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, event: &tracing::Event<'_>) {
+        let mut message = String::new();
+        event.record(&mut |field: &Field, value: &dyn Debug| {
+            if field.name() == "message" {
+                message = format!("{value:?}");
+            }
+        });
+        self.0.lock().unwrap().push(message);
+    }
+
+    fn enter(&self, _span: &Id) {}
+
+    fn exit(&self, _span: &Id) {}
+}
+
+/// Creates a headless app reading the crate's `assets` folder, with `Text` registered.
+pub(crate) fn app() -> App {
+    with_asset_plugins(App::new())
+}
+
+/// Creates a headless app like `app`, with another file asset source named `name` reading from `path`.
+fn app_with_source(name: &'static str, path: PathBuf) -> App {
+    let mut app = App::new();
+    app.register_asset_source(
+        name,
+        AssetSource::build().with_reader(move || Box::new(FileAssetReader::new(&path))),
+    );
+
+    with_asset_plugins(app)
+}
+
+/// Adds the asset plugins, the `Text` and `Blob` assets and their loaders to an app.
+fn with_asset_plugins(mut app: App) -> App {
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+        .init_asset::<Text>()
+        .init_asset::<Blob>()
+        .init_asset_loader::<TextLoader>()
+        .init_asset_loader::<BlobLoader>();
+
+    app
+}
+
+/// Gets the app's asset server.
+pub(crate) fn asset_server(app: &App) -> AssetServer {
+    app.world.resource::<AssetServer>().clone()
+}
+
+/// Updates the app until `done` returns true, panicking if it takes too long.
+pub(crate) fn run_until(app: &mut App, mut done: impl FnMut(&mut App) -> bool) {
+    for _ in 0..1000 {
+        app.update();
+        if done(app) {
+            return;
+        }
+
+        std::thread::sleep(Duration::from_millis(1));
+    }
+
+    panic!("the app didn't reach the expected state in time");
+}
+
+/// Gets the app's `AssetManager<Key, Text>`.
+pub(crate) fn manager(app: &App) -> &AssetManager<Key, Text> {
+    app.world.resource::<AssetManager<Key, Text>>()
+}
+
+/// Gets the app's `AssetManager<u32, Text>`, for tests needing more keys than `Key` has.
+fn numbered_manager(app: &App) -> &AssetManager<u32, Text> {
+    app.world.resource::<AssetManager<u32, Text>>()
+}
+
+#[test]
+fn budget_load_is_bounded_by_the_budget() {
+    let mut app = app();
+    let asset_manager = AssetManager::<u32, Text>::new(asset_server(&app));
+    (0..100).for_each(|key| asset_manager.insert(key, "a.txt"));
+
+    assert_eq!(asset_manager.load_within(Duration::ZERO), 0);
+    assert_eq!(asset_manager.state_counts().not_started, 100);
+
+    // Every read of the clock advances it by a millisecond. A promotion reads it twice, once to check the
+    // budget and once to start its load timer, so a 5ms budget allows exactly two promotions per call.
+    let time = Arc::new(Mutex::new(Instant::now()));
+    let clock = time.clone();
+    asset_manager.set_clock(Box::new(move || {
+        let mut time = clock.lock().unwrap();
+        *time += Duration::from_millis(1);
+        *time
+    }));
+    assert_eq!(asset_manager.load_within(Duration::from_millis(5)), 2);
+    assert_eq!(asset_manager.load_within(Duration::from_millis(5)), 2);
+    assert_eq!(asset_manager.progress().1, 4);
+    asset_manager.set_clock(Box::new(Instant::now));
+
+    app.insert_resource(asset_manager)
+        .insert_resource(LoadBudget(Duration::ZERO))
+        .add_systems(Update, asset_manager_budget_load::<u32, Text>);
+    app.update();
+    assert_eq!(
+        app.world.resource::<AssetManager<u32, Text>>().progress().1,
+        4
+    );
+
+    app.insert_resource(LoadBudget(Duration::from_secs(60)));
+    app.update();
+    assert_eq!(
+        app.world.resource::<AssetManager<u32, Text>>().progress().1,
+        100
+    );
+
+    run_until(&mut app, |app| {
+        app.world.resource::<AssetManager<u32, Text>>().progress() == (100, 100)
+    });
+    let handle = app
+        .world
+        .resource::<AssetManager<u32, Text>>()
+        .get(0)
+        .unwrap();
+    assert_eq!(
+        app.world
+            .resource::<Assets<Text>>()
+            .get(&handle)
+            .unwrap()
+            .content,
+        "a"
+    );
+}
+
+#[test]
+fn unpinned_assets_are_trimmed_once_unused() {
+    let mut app = app();
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    asset_manager.insert_many_loaded(&[(Key::A, "a.txt"), (Key::B, "b.txt")]);
+    app.insert_resource(asset_manager)
+        .add_systems(Update, asset_manager_trim_unused::<Key, Text>);
+    run_until(&mut app, |app| manager(app).state_counts().loaded == 2);
+
+    // Stored handles are strong, so nothing is trimmed until they're unpinned
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(manager(&app).state_counts().loaded, 2);
+
+    manager(&app).unpin_all();
+    let held = manager(&app).get_strong(Key::B).unwrap();
+    run_until(&mut app, |app| manager(app).peek(Key::A).is_none());
+    assert!(manager(&app).is_loaded(Key::B));
+
+    manager(&app).pin_all_loaded();
+    let handle = manager(&app).peek(Key::B).unwrap();
+    manager(&app).for_each(|_, asset| {
+        assert!(asset.handle().is_none_or(|stored| stored.is_strong()));
+    });
+    drop(held);
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(manager(&app).peek(Key::B), Some(handle));
+    assert!(manager(&app).is_loaded(Key::B));
+}
+
+#[test]
+fn paused_loading_promotes_nothing_until_resumed() {
+    let mut app = app();
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    asset_manager.insert_many(&[(Key::A, "a.txt"), (Key::B, "b.txt")]);
+    asset_manager.pause_loading();
+    app.insert_resource(asset_manager)
+        .insert_resource(LoadBudget(Duration::from_secs(60)))
+        .add_systems(Update, asset_manager_budget_load::<Key, Text>);
+
+    app.update();
+    assert!(manager(&app).is_loading_paused());
+    assert_eq!(manager(&app).load_within(Duration::from_secs(60)), 0);
+    assert_eq!(manager(&app).progress(), (0, 0));
+
+    manager(&app).resume_loading();
+    app.update();
+    assert_eq!(manager(&app).progress().1, 2);
+}
+
+#[test]
+#[cfg(not(target_arch = "wasm32"))]
+fn get_blocking_times_out_when_the_app_never_updates() {
+    let app = app();
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    asset_manager.insert(Key::A, "a.txt");
+
+    // Nothing updates the app, so the load never completes
+    assert_eq!(
+        asset_manager.get_blocking(Key::A, Duration::from_millis(10)),
+        Err(AssetManagerError::Timeout)
+    );
+    assert_eq!(
+        asset_manager.get_blocking(Key::B, Duration::from_millis(10)),
+        Err(AssetManagerError::MissingKey)
+    );
+}
+
+#[test]
+fn pending_bytes_drop_as_assets_finish_loading() {
+    let mut app = app();
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    asset_manager.insert_with_size(Key::A, "a.txt", 100);
+    asset_manager.insert_with_size(Key::B, "b.txt", 50);
+    assert_eq!(asset_manager.pending_bytes(), 150);
+
+    asset_manager.load(Key::A);
+    assert_eq!(asset_manager.pending_bytes(), 150);
+
+    app.insert_resource(asset_manager);
+    run_until(&mut app, |app| manager(app).is_loaded(Key::A));
+    assert_eq!(manager(&app).pending_bytes(), 50);
+}
+
+#[test]
+fn handle_cache_shares_handles_without_keeping_assets_loaded() {
+    let mut app = app();
+    let first = AssetManager::<Key, Text>::new(asset_server(&app));
+    let second = AssetManager::<Key, Text>::new(asset_server(&app));
+    let handle_cache = HandleCache::new();
+    first.set_handle_cache(handle_cache.clone());
+    second.set_handle_cache(handle_cache.clone());
+
+    first.insert_loaded(Key::A, "a.txt");
+    second.insert_loaded(Key::A, "./a.txt");
+    let id = first.peek(Key::A).unwrap().id();
+    assert_eq!(second.peek(Key::A).unwrap().id(), id);
+    assert_eq!(handle_cache.len(), 1);
+    run_until(&mut app, |app| {
+        asset_server(app).get_load_state(id) == Some(LoadState::Loaded)
+    });
+
+    // The cache's handles are weak, so removing the asset from both managers unloads it
+    drop((first.remove(Key::A), second.remove(Key::A)));
+    run_until(&mut app, |app| {
+        asset_server(app).get_load_state(id).is_none()
+    });
+
+    first.insert_loaded(Key::A, "a.txt");
+    assert_ne!(first.peek(Key::A).unwrap().id(), id);
+}
+
+#[test]
+fn inserted_managers_are_bound_to_the_apps_handle_cache() {
+    let mut app = app();
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    app.insert_asset_manager(asset_manager);
+
+    let other = AssetManager::<Key, Text>::new(asset_server(&app));
+    other.set_handle_cache(app.world.resource::<HandleCache<Text>>().clone());
+    manager(&app).insert_loaded(Key::A, "./a.txt");
+    other.insert_loaded(Key::A, "a.txt");
+    assert_eq!(manager(&app).peek(Key::A), other.peek(Key::A));
+}
+
+#[test]
+fn promote_hook_fires_once_per_promotion() {
+    let app = app();
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    let promoted = Arc::new(Mutex::new(Vec::new()));
+    let recorded = promoted.clone();
+    asset_manager.set_on_promote(Box::new(move |key, path| {
+        recorded.lock().unwrap().push((key, path.to_owned()));
+    }));
+    asset_manager.insert(Key::A, "a.txt");
+    asset_manager.insert_loaded(Key::B, "b.txt");
+
+    asset_manager.get(Key::A);
+    asset_manager.get(Key::A);
+    asset_manager.load(Key::A);
+    asset_manager.get(Key::B);
+    assert_eq!(
+        *promoted.lock().unwrap(),
+        vec![(Key::A, "a.txt".to_owned())]
+    );
+}
+
+#[test]
+fn load_many_detailed_reports_only_new_promotions() {
+    let app = app();
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    asset_manager.insert(Key::A, "a.txt");
+
+    let first = asset_manager.load_many_detailed(&[Key::A, Key::B]);
+    assert_eq!(first, HashMap::from([(Key::A, true), (Key::B, false)]));
+
+    let second = asset_manager.load_many_detailed(&[Key::A, Key::B]);
+    assert!(second.values().all(|loaded| !loaded));
+    assert_eq!(second.len(), 2);
+}
+
+#[test]
+fn hot_reload_system_is_inert_when_not_watching() {
+    let mut app = app();
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    asset_manager.insert_loaded(Key::A, "a.txt");
+    app.insert_resource(asset_manager)
+        .add_asset_manager_events::<Key, Text>();
+    run_until(&mut app, |app| manager(app).is_loaded(Key::A));
+    assert!(!manager(&app).is_watching());
+
+    let id = manager(&app).peek(Key::A).unwrap().id();
+    app.world.send_event(AssetEvent::Modified { id });
+    app.update();
+    assert!(app
+        .world
+        .resource::<Events<AssetModified<Key, Text>>>()
+        .is_empty());
+
+    // Without the run condition, the same event is picked up
+    app.add_systems(PostUpdate, asset_manager_hot_reload::<Key, Text>);
+    app.world.send_event(AssetEvent::Modified { id });
+    app.update();
+    let modified = app.world.resource::<Events<AssetModified<Key, Text>>>();
+    let keys: Vec<Key> = modified
+        .get_reader()
+        .read(modified)
+        .map(|event| event.key)
+        .collect();
+    assert_eq!(keys, vec![Key::A]);
+}
+
+#[test]
+fn promote_hook_may_call_get_reentrantly() {
+    let app = app();
+    let asset_manager = Arc::new(AssetManager::<Key, Text>::new(asset_server(&app)));
+    asset_manager.insert_many(&[(Key::A, "a.txt"), (Key::B, "b.txt")]);
+    let reentrant = Arc::downgrade(&asset_manager);
+    asset_manager.set_on_promote(Box::new(move |key, _| {
+        let asset_manager = reentrant.upgrade().unwrap();
+        assert!(asset_manager.get(key).is_some());
+        asset_manager.get(Key::B);
+    }));
+
+    let (sender, receiver) = mpsc::channel();
+    let getter = asset_manager.clone();
+    thread::spawn(move || sender.send(getter.get(Key::A).is_some()).unwrap());
+    assert_eq!(receiver.recv_timeout(Duration::from_secs(5)), Ok(true));
+    assert!(asset_manager.peek(Key::B).is_some());
+}
+
+#[test]
+fn preload_assets_stay_lazy_until_the_preload_phase() {
+    let mut app = app();
+    app.add_plugins(AssetManagerPlugin::new(|asset_server| {
+        let asset_manager = AssetManager::<Key, Text>::new(asset_server);
+        asset_manager.insert_preload(Key::A, "a.txt");
+        asset_manager.insert(Key::B, "b.txt");
+
+        asset_manager
+    }));
+    assert!(manager(&app).peek(Key::A).is_none());
+
+    app.update();
+    assert!(manager(&app).peek(Key::A).is_some());
+    assert!(manager(&app).peek(Key::B).is_none());
+    run_until(&mut app, |app| manager(app).is_loaded(Key::A));
+}
+
+#[test]
+fn plugins_track_the_progress_of_every_manager() {
+    let mut app = app();
+    app.add_plugins((
+        AssetManagerPlugin::new(|asset_server| {
+            let asset_manager = AssetManager::<Key, Text>::new(asset_server);
+            asset_manager.insert_loaded(Key::A, "a.txt");
+            asset_manager.insert(Key::B, "b.txt");
+
+            asset_manager
+        }),
+        AssetManagerPlugin::new(|asset_server| {
+            let asset_manager = AssetManager::<u32, Text>::new(asset_server);
+            asset_manager.insert_loaded(1, "c.txt");
+            asset_manager.insert_preload(2, "dependent.txt");
+
+            asset_manager
+        }),
+    ));
+    assert!(app.world.contains_resource::<AssetManager<Key, Text>>());
+    assert!(app.world.contains_resource::<AssetManager<u32, Text>>());
+    assert_eq!(
+        *app.world.resource::<LoadProgress>(),
+        LoadProgress::default()
+    );
+
+    run_until(&mut app, |app| {
+        app.world.resource::<LoadProgress>().loaded == 3
+    });
+    // Lazy assets aren't counted until they're requested
+    assert_eq!(
+        *app.world.resource::<LoadProgress>(),
+        LoadProgress {
+            loaded: 3,
+            total: 3
+        }
+    );
+    assert_eq!(numbered_manager(&app).state_counts().loaded, 2);
+}
+
+#[test]
+fn init_asset_manager_preloads_assets_inserted_during_startup() {
+    let mut app = app();
+    app.init_asset_manager::<Key, Text>().add_systems(
+        Startup,
+        |asset_manager: Res<AssetManager<Key, Text>>| {
+            asset_manager.insert_preload(Key::A, "a.txt");
+        },
+    );
+
+    app.update();
+    assert!(manager(&app).peek(Key::A).is_some());
+}
+
+struct KeyAssetsPlugin;
+
+impl Plugin for KeyAssetsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset_manager::<Key, Text>().add_systems(
+            Startup,
+            |asset_manager: Res<AssetManager<Key, Text>>| {
+                asset_manager.insert_preload(Key::A, "a.txt");
+            },
+        );
+    }
+}
+
+#[test]
+fn init_asset_manager_registers_every_system_once() {
+    let mut app = app();
+    app.add_plugins(KeyAssetsPlugin)
+        .init_asset_manager::<Key, Text>();
+    let receiver = manager(&app).observe_key(Key::A);
+    let mut loaded = app
+        .world
+        .resource::<Events<AssetLoaded<Key, Text>>>()
+        .get_reader();
+
+    let mut loaded_keys = Vec::new();
+    let mut states = Vec::new();
+    run_until(&mut app, |app| {
+        let events = app.world.resource::<Events<AssetLoaded<Key, Text>>>();
+        loaded_keys.extend(loaded.read(events).map(|event| event.key));
+        states.extend(receiver.try_iter());
+
+        states.contains(&LoadState::Loaded)
+    });
+    app.update();
+    let events = app.world.resource::<Events<AssetLoaded<Key, Text>>>();
+    loaded_keys.extend(loaded.read(events).map(|event| event.key));
+
+    assert!(manager(&app).peek(Key::A).is_some());
+    assert_eq!(loaded_keys, vec![Key::A]);
+}
+
+#[test]
+fn only_strong_handles_from_get_many_with_outlive_the_manager() {
+    let mut app = app();
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    asset_manager.insert_many(&[(Key::A, "a.txt"), (Key::B, "b.txt")]);
+    let weak = asset_manager.get_many_with(&[Key::A], Strength::Weak);
+    let strong = asset_manager.get_many_with(&[Key::B], Strength::Strong);
+    assert!(!weak[0].is_strong());
+    assert!(strong[0].is_strong());
+    app.insert_resource(asset_manager);
+    run_until(&mut app, |app| manager(app).state_counts().loaded == 2);
+
+    manager(&app).remove_many(&[Key::A, Key::B]);
+    run_until(&mut app, |app| {
+        asset_server(app).get_load_state(&weak[0]).is_none()
+    });
+    assert!(app.world.resource::<Assets<Text>>().contains(&strong[0]));
+}
+
+#[test]
+fn handle_strength_applies_to_later_gets() {
+    let mut app = app();
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    asset_manager.insert_many(&[(Key::A, "a.txt"), (Key::B, "b.txt")]);
+    assert_eq!(asset_manager.handle_strength(), Strength::Weak);
+    let before = asset_manager.get(Key::A).unwrap();
+    asset_manager.set_handle_strength(Strength::Strong);
+    let after = asset_manager.get(Key::B).unwrap();
+    assert!(!before.is_strong());
+    assert!(after.is_strong());
+    app.insert_resource(asset_manager);
+    run_until(&mut app, |app| manager(app).state_counts().loaded == 2);
+
+    manager(&app).clear();
+    run_until(&mut app, |app| {
+        asset_server(app).get_load_state(&before).is_none()
+    });
+    assert!(app.world.resource::<Assets<Text>>().contains(&after));
+}
+
+#[test]
+fn one_call_event_setup_sends_loaded_and_failed_events() {
+    let mut app = app();
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    asset_manager.insert_loaded(Key::A, "a.txt");
+    asset_manager.insert_loaded(Key::B, "missing.txt");
+    app.insert_resource(asset_manager)
+        .add_asset_manager_events::<Key, Text>();
+
+    let mut changed = app
+        .world
+        .resource::<Events<AssetStateChanged<Key, Text>>>()
+        .get_reader();
+    let mut loaded = app
+        .world
+        .resource::<Events<AssetLoaded<Key, Text>>>()
+        .get_reader();
+    let mut failed = app
+        .world
+        .resource::<Events<AssetFailed<Key, Text>>>()
+        .get_reader();
+    let (mut states, mut loaded_keys, mut failed_keys) = (Vec::new(), Vec::new(), Vec::new());
+    run_until(&mut app, |app| {
+        let events = app.world.resource::<Events<AssetStateChanged<Key, Text>>>();
+        states.extend(changed.read(events).map(|event| (event.key, event.state)));
+        let events = app.world.resource::<Events<AssetLoaded<Key, Text>>>();
+        loaded_keys.extend(loaded.read(events).map(|event| event.key));
+        let events = app.world.resource::<Events<AssetFailed<Key, Text>>>();
+        failed_keys.extend(failed.read(events).map(|event| event.key));
+
+        !loaded_keys.is_empty() && !failed_keys.is_empty()
+    });
+
+    assert_eq!(loaded_keys, vec![Key::A]);
+    assert_eq!(failed_keys, vec![Key::B]);
+    assert!(states.contains(&(Key::A, LoadState::Loaded)));
+    assert!(states.contains(&(Key::B, LoadState::Failed)));
+}
+
+#[test]
+fn load_all_except_leaves_skipped_keys_lazy() {
+    let mut app = app();
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    asset_manager.insert_many(&[(Key::A, "a.txt"), (Key::B, "b.txt")]);
+    asset_manager.load_all_except(&[Key::B]);
+    app.insert_resource(asset_manager);
+    run_until(&mut app, |app| manager(app).is_loaded(Key::A));
+
+    assert!(manager(&app).peek(Key::B).is_none());
+    assert_eq!(manager(&app).state_counts().not_started, 1);
+}
+
+#[test]
+fn get_with_state_reports_loading_then_loaded() {
+    let mut app = app();
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    asset_manager.insert(Key::A, "a.txt");
+    let (handle, state) = asset_manager.get_with_state(Key::A).unwrap();
+    assert_eq!(state, LoadState::Loading);
+    app.insert_resource(asset_manager);
+    run_until(&mut app, |app| manager(app).is_loaded(Key::A));
+
+    let (loaded_handle, state) = manager(&app).get_with_state(Key::A).unwrap();
+    assert_eq!(state, LoadState::Loaded);
+    assert_eq!(loaded_handle.id(), handle.id());
+    assert!(manager(&app).get_with_state(Key::B).is_none());
+}
+
+#[derive(States, Debug, Clone, PartialEq, Eq, Hash, Default)]
+enum GameState {
+    #[default]
+    Menu,
+    Level,
+}
+
+#[test]
+fn state_scoped_assets_are_removed_when_their_state_exits() {
+    let mut app = app();
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    asset_manager.insert_state_scoped(Key::A, "a.txt", GameState::Menu);
+    asset_manager.insert_state_scoped(Key::B, "b.txt", GameState::Level);
+    app.add_state::<GameState>()
+        .insert_resource(asset_manager)
+        .add_state_scoped_assets::<Key, Text, _>(GameState::Menu)
+        .add_state_scoped_assets::<Key, Text, _>(GameState::Level);
+    app.update();
+    assert_eq!(manager(&app).len(), 2);
+
+    app.world
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::Level);
+    app.update();
+    assert!(!manager(&app).contains_key(Key::A));
+    assert!(manager(&app).contains_key(Key::B));
+
+    app.world
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::Menu);
+    app.update();
+    assert!(manager(&app).is_empty());
+}
+
+#[test]
+fn state_counts_count_each_load_state() {
+    let mut app = app();
+    let asset_manager = AssetManager::<u32, Text>::new(asset_server(&app));
+    asset_manager.insert_loaded(1, "a.txt");
+    asset_manager.insert_loaded(2, "missing.txt");
+    asset_manager.insert(3, "b.txt");
+    asset_manager.insert(4, "c.txt");
+    app.insert_resource(asset_manager);
+    run_until(&mut app, |app| {
+        let counts = app
+            .world
+            .resource::<AssetManager<u32, Text>>()
+            .state_counts();
+        counts.loaded == 1 && counts.failed == 1
+    });
+
+    let asset_manager = numbered_manager(&app);
+    asset_manager.load(4);
+    assert_eq!(
+        asset_manager.state_counts(),
+        StateCounts {
+            loading: 1,
+            loaded: 1,
+            failed: 1,
+            not_started: 1,
+        }
+    );
+}
+
+#[test]
+fn rehomed_assets_resolve_into_the_new_collection() {
+    let mut app = app();
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    asset_manager.insert_loaded(Key::A, "a.txt");
+    app.insert_resource(asset_manager);
+    run_until(&mut app, |app| manager(app).is_loaded(Key::A));
+    let before = manager(&app).get(Key::A).unwrap();
+
+    let mut assets_to = Assets::<Text>::default();
+    app.world
+        .resource_scope(|world, mut assets_from: Mut<Assets<Text>>| {
+            let asset_manager = world.resource::<AssetManager<Key, Text>>();
+            asset_manager.rehome(&mut assets_from, &mut assets_to);
+        });
+
+    let after = manager(&app).get(Key::A).unwrap();
+    assert_eq!(assets_to.get(&after).unwrap().content, "a");
+    assert!(!app.world.resource::<Assets<Text>>().contains(&before));
+}
+
+#[test]
+fn pinned_assets_survive_until_unpinned() {
+    let mut app = app();
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    asset_manager.insert_many_loaded(&[(Key::A, "a.txt"), (Key::B, "b.txt")]);
+    app.insert_resource(asset_manager);
+    run_until(&mut app, |app| manager(app).state_counts().loaded == 2);
+    let handles = manager(&app).get_many(&[Key::A, Key::B]);
+
+    manager(&app).unpin_all();
+    manager(&app).pin_all_loaded();
+    (0..10).for_each(|_| app.update());
+    let assets = app.world.resource::<Assets<Text>>();
+    assert!(handles.iter().all(|handle| assets.contains(handle)));
+
+    manager(&app).unpin_all();
+    run_until(&mut app, |app| {
+        let assets = app.world.resource::<Assets<Text>>();
+        handles.iter().all(|handle| !assets.contains(handle))
+    });
+}
+
+#[test]
+fn dependency_gate_waits_for_dependencies() {
+    let mut app = app();
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    asset_manager.insert_loaded(Key::A, "broken_dependent.txt");
+    asset_manager.insert_loaded(Key::B, "dependent.txt");
+    app.insert_resource(asset_manager);
+    run_until(&mut app, |app| {
+        manager(app).is_loaded(Key::A) && manager(app).is_fully_loaded(Key::B)
+    });
+    (0..10).for_each(|_| app.update());
+
+    assert!(!manager(&app).is_fully_loaded(Key::A));
+    assert_eq!(manager(&app).progress(), (1, 2));
+}
+
+#[test]
+fn observe_key_delivers_only_the_watched_keys_states() {
+    let mut app = app();
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    asset_manager.insert_loaded(Key::A, "a.txt");
+    asset_manager.insert_loaded(Key::B, "missing.txt");
+    let receiver = asset_manager.observe_key(Key::A);
+    app.insert_resource(asset_manager);
+
+    let mut states = Vec::new();
+    run_until(&mut app, |app| {
+        manager(app).notify_observers();
+        states.extend(receiver.try_iter());
+        states.last() == Some(&LoadState::Loaded)
+            && manager(app).load_state(Key::B) == Some(LoadState::Failed)
+    });
+    manager(&app).notify_observers();
+
+    assert!(!states.contains(&LoadState::Failed));
+    assert!(receiver.try_recv().is_err());
+}
+
+#[test]
+fn bulk_removal_shrinks_capacity_only_with_auto_shrink() {
+    let app = app();
+    let keys: Vec<u32> = (0..256).collect();
+    let paths: Vec<String> = keys.iter().map(|key| format!("{key}.txt")).collect();
+    let capacity_after_removal = |auto_shrink| {
+        let asset_manager = AssetManager::<u32, Text>::new(asset_server(&app));
+        asset_manager.set_auto_shrink(auto_shrink);
+        keys.iter()
+            .zip(&paths)
+            .for_each(|(key, path)| asset_manager.insert(*key, path));
+        asset_manager.remove_many(&keys[..250]);
+
+        asset_manager.capacity()
+    };
+
+    // Removals leave tombstones behind, so the capacity of an unshrunk map drops a little too
+    assert!(capacity_after_removal(false) >= keys.len());
+    assert!(capacity_after_removal(true) < keys.len() / 16);
+}
+
+#[test]
+fn ensure_loading_reports_whether_the_key_exists() {
+    let app = app();
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    asset_manager.insert(Key::A, "a.txt");
+
+    assert!(!asset_manager.ensure_loading(Key::B));
+    assert!(asset_manager.peek(Key::A).is_none());
+    assert!(asset_manager.ensure_loading(Key::A));
+    assert_eq!(asset_manager.load_state(Key::A), Some(LoadState::Loading));
+}
+
+#[test]
+fn leased_groups_are_released_on_despawn() {
+    let mut app = app();
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    asset_manager.insert_many(&[(Key::A, "a.txt"), (Key::B, "b.txt")]);
+    let entity = app.world.spawn_empty().id();
+    let mut queue = CommandQueue::default();
+    let mut commands = Commands::new(&mut queue, &app.world);
+    asset_manager.lease_group_for_entity(&[Key::A, Key::B], &mut commands, entity);
+    queue.apply(&mut app.world);
+    app.insert_resource(asset_manager);
+    run_until(&mut app, |app| manager(app).state_counts().loaded == 2);
+    let handles = manager(&app).get_many(&[Key::A, Key::B]);
+
+    manager(&app).unpin_all();
+    (0..10).for_each(|_| app.update());
+    let assets = app.world.resource::<Assets<Text>>();
+    assert!(handles.iter().all(|handle| assets.contains(handle)));
+
+    app.world.despawn(entity);
+    run_until(&mut app, |app| {
+        let assets = app.world.resource::<Assets<Text>>();
+        handles.iter().all(|handle| !assets.contains(handle))
+    });
+}
+
+#[test]
+fn peek_many_only_yields_loaded_assets() {
+    let app = app();
+    let asset_manager = AssetManager::<u32, Text>::new(asset_server(&app));
+    asset_manager.insert_loaded(1, "a.txt");
+    asset_manager.insert(2, "b.txt");
+
+    let handles = asset_manager.peek_many(&[2, 1, 3]);
+    assert!(handles[0].is_none());
+    assert_eq!(
+        handles[1].as_ref().map(Handle::id),
+        asset_manager.peek(1).map(|handle| handle.id())
+    );
+    assert!(handles[1]
+        .as_ref()
+        .is_some_and(|handle| !handle.is_strong()));
+    assert!(handles[2].is_none());
+    assert!(asset_manager.peek(2).is_none());
+}
+
+#[test]
+fn load_durations_are_measured_by_the_managers_clock() {
+    let mut app = app();
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    let start = Instant::now();
+    let time = Arc::new(Mutex::new(start));
+    let clock = time.clone();
+    asset_manager.set_clock(Box::new(move || *clock.lock().unwrap()));
+    asset_manager.insert_many(&[(Key::A, "a.txt"), (Key::B, "b.txt")]);
+
+    asset_manager.load(Key::A);
+    *time.lock().unwrap() += Duration::from_secs(2);
+    asset_manager.load(Key::B);
+    app.insert_resource(asset_manager);
+    run_until(&mut app, |app| manager(app).state_counts().loaded == 2);
+    assert!(manager(&app).load_durations().is_empty());
+
+    *time.lock().unwrap() = start + Duration::from_secs(5);
+    manager(&app).record_load_completions();
+    let mut durations = manager(&app).load_durations();
+    durations.sort();
+    assert_eq!(
+        durations,
+        vec![
+            (Key::A, Duration::from_secs(5)),
+            (Key::B, Duration::from_secs(3)),
+        ]
+    );
+}
+
+#[test]
+fn clear_tags_keeps_assets_registered() {
+    let app = app();
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    asset_manager.insert_tagged_many(&[
+        (Key::A, "a.txt", &["level", "hud"]),
+        (Key::B, "b.txt", &["level"]),
+    ]);
+
+    asset_manager.clear_tags();
+    assert!(asset_manager.keys_with_tag("level").is_empty());
+    assert!(asset_manager.keys_with_tag("hud").is_empty());
+    assert_eq!(asset_manager.len(), 2);
+    assert!(asset_manager.get(Key::A).is_some());
+}
+
+#[test]
+fn mixed_manager_counts_follow_inserts_and_removals() {
+    let app = app();
+    let asset_manager = mixed_asset_manager!(<u32, Text> binds asset_server(&app).clone(), {
+        LoadStyle::Loaded, 1 => "a.txt",
+        LoadStyle::Lazy, 2 => "b.txt",
+    });
+    assert_eq!(asset_manager.len(), 2);
+    assert!(asset_manager.contains_key(1));
+    assert!(asset_manager.contains_key(2));
+    assert!(!asset_manager.contains_key(3));
+
+    asset_manager.insert(3, "c.txt");
+    assert_eq!(asset_manager.len(), 3);
+    assert!(asset_manager.contains_key(3));
+
+    asset_manager.remove_many(&[1, 2, 3]);
+    assert!(asset_manager.is_empty());
+    assert!(!asset_manager.contains_key(1));
+}
+
+#[test]
+fn get_instrumented_reports_a_miss_then_a_hit() {
+    let app = app();
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    asset_manager.insert(Key::A, "a.txt");
+
+    let (first, hit) = asset_manager.get_instrumented(Key::A).unwrap();
+    assert!(!hit);
+    let (second, hit) = asset_manager.get_instrumented(Key::A).unwrap();
+    assert!(hit);
+    assert_eq!(first.id(), second.id());
+    assert!(asset_manager.get_instrumented(Key::B).is_none());
+}
+
+#[test]
+fn key_index_resolves_loaded_asset_ids() {
+    let mut app = app();
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    asset_manager.insert_loaded(Key::A, "a.txt");
+    asset_manager.insert(Key::B, "b.txt");
+    let id = asset_manager.peek(Key::A).unwrap().id();
+    app.insert_asset_manager(asset_manager);
+    app.update();
+
+    let index = app.world.resource::<AssetKeyIndex<Key, Text>>();
+    assert_eq!(index.get(id), Some(&Key::A));
+
+    let id = manager(&app).get(Key::B).unwrap().id();
+    app.update();
+    let index = app.world.resource::<AssetKeyIndex<Key, Text>>();
+    assert_eq!(index.get(id), Some(&Key::B));
+}
+
+/// Gets the content of a loaded `Text` asset.
+fn content(app: &App, handle: &Handle<Text>) -> Option<String> {
+    let assets = app.world.resource::<Assets<Text>>();
+    assets.get(handle).map(|text| text.content.clone())
+}
+
+#[test]
+fn reload_keeps_handle_ids_while_the_data_changes() {
+    let dir = env::temp_dir().join(format!("bevy_asset_manager_reload_{}", process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("reload.txt"), "before").unwrap();
+    let mut app = app_with_source("temp", dir.clone());
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    asset_manager.set_source(AssetSourceId::from("temp"));
+    asset_manager.insert_loaded(Key::A, "reload.txt");
+    app.insert_resource(asset_manager);
+    run_until(&mut app, |app| manager(app).is_loaded(Key::A));
+    let before = manager(&app).get(Key::A).unwrap();
+    assert_eq!(content(&app, &before).as_deref(), Some("before"));
+
+    fs::write(dir.join("reload.txt"), "after").unwrap();
+    manager(&app).reload(Key::A);
+    run_until(&mut app, |app| {
+        content(app, &before).as_deref() == Some("after")
+    });
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(manager(&app).get(Key::A).unwrap().id(), before.id());
+}
+
+#[test]
+fn reload_all_swaps_handles_of_assets_whose_source_changed() {
+    let mut app = app_with_source("alt", PathBuf::from("assets/alt"));
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    asset_manager.insert_loaded(Key::A, "a.txt");
+    app.insert_resource(asset_manager);
+    run_until(&mut app, |app| manager(app).is_loaded(Key::A));
+    let before = manager(&app).get(Key::A).unwrap();
+    assert_eq!(content(&app, &before).as_deref(), Some("a"));
+
+    manager(&app).set_source(AssetSourceId::from("alt"));
+    manager(&app).reload_all();
+    run_until(&mut app, |app| manager(app).is_loaded(Key::A));
+    let after = manager(&app).get(Key::A).unwrap();
+
+    assert_ne!(after.id(), before.id());
+    assert_eq!(content(&app, &after).as_deref(), Some("alt a"));
+}
+
+#[test]
+fn get_iter_collected_accepts_a_hash_set() {
+    let app = app();
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    asset_manager.insert_many(&[(Key::A, "a.txt"), (Key::B, "b.txt")]);
+
+    let keys: HashSet<Key> = [Key::A, Key::B].into_iter().collect();
+    let handles = asset_manager.get_iter_collected(keys);
+    assert_eq!(handles.len(), 2);
+    assert_eq!(asset_manager.state_counts().not_started, 0);
+}
+
+#[test]
+fn remap_paths_updates_lazy_and_loaded_assets() {
+    let mut app = app();
+    let asset_manager = AssetManager::<u32, Text>::new(asset_server(&app));
+    asset_manager.insert_loaded(1, "a.txt");
+    asset_manager.insert(2, "a.txt");
+    asset_manager.insert_loaded(3, "b.txt");
+    app.insert_resource(asset_manager);
+    run_until(&mut app, |app| {
+        numbered_manager(app).state_counts().loaded == 2
+    });
+    let before = numbered_manager(&app).get(1).unwrap();
+
+    numbered_manager(&app).remap_paths(|_, path| (path == "a.txt").then(|| "alt/a.txt".to_owned()));
+    assert_eq!(
+        numbered_manager(&app).path_of(1).as_deref(),
+        Some("alt/a.txt")
+    );
+    assert_eq!(
+        numbered_manager(&app).path_of(2).as_deref(),
+        Some("alt/a.txt")
+    );
+    assert_eq!(numbered_manager(&app).path_of(3).as_deref(), Some("b.txt"));
+    assert!(numbered_manager(&app).peek(2).is_none());
+
+    run_until(&mut app, |app| numbered_manager(app).is_loaded(1));
+    let after = numbered_manager(&app).get(1).unwrap();
+    assert_ne!(after.id(), before.id());
+    assert_eq!(content(&app, &after).as_deref(), Some("alt a"));
+}
+
+#[test]
+fn managers_rebound_to_another_asset_server_load_against_it() {
+    let mut app = app();
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    asset_manager.insert_loaded(Key::A, "a.txt");
+    asset_manager.add_tag(Key::A, "hud");
+    app.insert_resource(asset_manager);
+    run_until(&mut app, |app| manager(app).is_loaded(Key::A));
+
+    let mut other = self::app();
+    let rebound = manager(&app).with_asset_server(asset_server(&other));
+    assert!(rebound.peek(Key::A).is_none());
+    assert_eq!(rebound.path_of(Key::A).as_deref(), Some("a.txt"));
+    assert_eq!(rebound.keys_with_tag("hud"), vec![Key::A]);
+    let handle = rebound.get_strong(Key::A).unwrap();
+    other.insert_resource(rebound);
+    run_until(&mut other, |other| manager(other).is_loaded(Key::A));
+
+    assert_eq!(content(&other, &handle).as_deref(), Some("a"));
+    assert!(manager(&app).is_loaded(Key::A));
+}
+
+#[test]
+fn path_of_returns_the_registered_path() {
+    let app = app();
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    asset_manager.insert_loaded(Key::A, "a.txt");
+
+    assert!(asset_manager.peek(Key::A).is_some());
+    assert_eq!(asset_manager.path_of(Key::A).as_deref(), Some("a.txt"));
+    assert_eq!(asset_manager.path_of(Key::B), None);
+}
+
+#[test]
+fn reload_loads_lazy_assets_and_reloads_loaded_ones_in_place() {
+    let mut app = app();
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    asset_manager.insert_loaded(Key::A, "a.txt");
+    asset_manager.insert(Key::B, "b.txt");
+    app.insert_resource(asset_manager);
+    run_until(&mut app, |app| manager(app).is_loaded(Key::A));
+    let before = manager(&app).peek(Key::A).unwrap();
+
+    manager(&app).reload_many(&[Key::A, Key::B]);
+    assert!(manager(&app).peek(Key::B).is_some());
+    run_until(&mut app, |app| manager(app).state_counts().loaded == 2);
+
+    assert_eq!(manager(&app).peek(Key::A).unwrap().id(), before.id());
+}
+
+#[test]
+fn all_assets_loaded_is_sent_once_requested_assets_load() {
+    let mut app = app();
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    asset_manager.insert_loaded(Key::A, "dependent.txt");
+    asset_manager.insert(Key::B, "missing.txt");
+    app.insert_resource(asset_manager)
+        .add_event::<AllAssetsLoaded<Key, Text>>()
+        .add_systems(Update, check_all_loaded::<Key, Text>);
+
+    let mut reader = app
+        .world
+        .resource::<Events<AllAssetsLoaded<Key, Text>>>()
+        .get_reader();
+    let mut sent = 0;
+    run_until(&mut app, |app| {
+        let events = app.world.resource::<Events<AllAssetsLoaded<Key, Text>>>();
+        sent += reader.read(events).count();
+        sent > 0
+    });
+    assert!(manager(&app).is_loaded(Key::A));
+    assert!(manager(&app).peek(Key::B).is_none());
+
+    (0..10).for_each(|_| app.update());
+    let events = app.world.resource::<Events<AllAssetsLoaded<Key, Text>>>();
+    assert_eq!(sent + reader.read(events).count(), 1);
+}
+
+#[test]
+fn get_or_insert_keeps_existing_entries() {
+    let app = app();
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    asset_manager.insert(Key::A, "a.txt");
+
+    let existing = asset_manager.get_or_insert(Key::A, "c.txt");
+    assert_eq!(asset_manager.path_of(Key::A).as_deref(), Some("a.txt"));
+    assert_eq!(
+        Some(existing.id()),
+        asset_manager.peek(Key::A).map(|handle| handle.id())
+    );
+
+    let inserted = asset_manager.get_or_insert(Key::B, "b.txt");
+    assert_eq!(asset_manager.path_of(Key::B).as_deref(), Some("b.txt"));
+    assert_eq!(
+        Some(inserted.id()),
+        asset_manager.peek(Key::B).map(|handle| handle.id())
+    );
+    assert_eq!(
+        asset_manager.get_or_insert(Key::B, "c.txt").id(),
+        inserted.id()
+    );
+}
+
+#[test]
+fn untyped_managers_hold_assets_of_different_types() {
+    let mut app = app();
+    let untyped = UntypedAssetManager::<Key>::new(asset_server(&app));
+    untyped.insert(Key::A, "a.txt");
+    untyped.insert(Key::B, "blob.bin");
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    asset_manager.insert_untyped(Key::A, "a.txt");
+    asset_manager.insert_untyped(Key::B, "blob.bin");
+    app.insert_resource(untyped).insert_resource(asset_manager);
+    run_until(&mut app, |app| {
+        let untyped = app.world.resource::<UntypedAssetManager<Key>>();
+        untyped.get_untyped(Key::A).is_some()
+            && untyped.get_untyped(Key::B).is_some()
+            && manager(app).get_untyped_resolved(Key::A).is_some()
+            && manager(app).get_untyped_resolved(Key::B).is_some()
+    });
+
+    let untyped = app.world.resource::<UntypedAssetManager<Key>>();
+    let text = untyped.get_typed::<Text>(Key::A).unwrap();
+    let blob = untyped.get_typed::<Blob>(Key::B).unwrap();
+    assert!(untyped.get_typed::<Blob>(Key::A).is_none());
+    assert!(untyped.get_typed::<Text>(Key::B).is_none());
+    assert_eq!(content(&app, &text).as_deref(), Some("a"));
+    assert_eq!(
+        app.world
+            .resource::<Assets<Blob>>()
+            .get(&blob)
+            .unwrap()
+            .0
+            .len(),
+        4
+    );
+
+    assert_eq!(
+        manager(&app).get_as::<Text>(Key::A).unwrap().id(),
+        text.id()
+    );
+    assert_eq!(
+        manager(&app).get_as::<Blob>(Key::B).unwrap().id(),
+        blob.id()
+    );
+    assert!(manager(&app).get_as::<Text>(Key::B).is_none());
+    manager(&app).clear();
+    assert!(manager(&app).get_untyped_resolved(Key::A).is_none());
+}
+
+#[test]
+fn untyped_assets_follow_removals_renames_and_the_source() {
+    let mut app = app_with_source("alt", PathBuf::from("assets/alt"));
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    asset_manager.set_source(AssetSourceId::from("alt"));
+    asset_manager.insert_untyped(Key::A, "a.txt");
+    asset_manager.insert_untyped(Key::B, "blob.bin");
+
+    assert!(asset_manager.remove(Key::B).is_none());
+    assert!(!asset_manager.rename_key(Key::B, Key::A));
+    assert!(asset_manager.rename_key(Key::A, Key::B));
+    app.insert_resource(asset_manager);
+    run_until(&mut app, |app| {
+        manager(app).get_as::<Text>(Key::B).is_some()
+    });
+
+    let text = manager(&app).get_as::<Text>(Key::B).unwrap();
+    assert_eq!(content(&app, &text).as_deref(), Some("alt a"));
+    assert!(manager(&app).get_untyped_resolved(Key::A).is_none());
+    assert_eq!(manager(&app).remove_if(|_, path| path == "a.txt"), 1);
+    assert!(manager(&app).get_as::<Text>(Key::B).is_none());
+}
+
+#[test]
+fn clear_empties_the_manager_while_clear_loaded_keeps_its_paths() {
+    let app = app();
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    asset_manager.insert_many_loaded(&[(Key::A, "a.txt"), (Key::B, "b.txt")]);
+
+    asset_manager.clear_loaded();
+    assert_eq!(asset_manager.len(), 2);
+    assert_eq!(
+        asset_manager
+            .entries()
+            .into_iter()
+            .filter(|(_, style)| *style == LoadStyle::Lazy)
+            .count(),
+        2
+    );
+    assert_eq!(asset_manager.path_of(Key::A).as_deref(), Some("a.txt"));
+
+    asset_manager.clear();
+    assert_eq!(asset_manager.len(), 0);
+    assert!(asset_manager.path_of(Key::A).is_none());
+}
+
+#[test]
+fn concurrent_gets_promote_each_asset_once() {
+    let app = app();
+    let asset_manager = Arc::new(AssetManager::<u32, Text>::new(asset_server(&app)));
+    let keys: Vec<u32> = (0..64).collect();
+    let paths: Vec<String> = keys.iter().map(|key| format!("{key}.txt")).collect();
+    keys.iter()
+        .zip(&paths)
+        .for_each(|(key, path)| asset_manager.insert(*key, path));
+    let promotions = Arc::new(Mutex::new(HashMap::<u32, usize>::new()));
+    let counter = promotions.clone();
+    asset_manager.set_on_promote(Box::new(move |key, _| {
+        *counter.lock().unwrap().entry(key).or_default() += 1;
+    }));
+
+    let (sender, receiver) = mpsc::channel();
+    for reader in 0..8 {
+        let (asset_manager, keys, sender) = (asset_manager.clone(), keys.clone(), sender.clone());
+        thread::spawn(move || {
+            // Readers walk the keys from different starting points, so they race on different entries
+            let ids: HashMap<u32, _> = keys
+                .iter()
+                .cycle()
+                .skip(reader * 8)
+                .take(keys.len() * 4)
+                .map(|key| (*key, asset_manager.get(*key).unwrap().id()))
+                .collect();
+            sender.send(ids).unwrap();
+        });
+    }
+    let writer = asset_manager.clone();
+    thread::spawn(move || {
+        writer.load_all();
+        sender.send(HashMap::new()).unwrap();
+    });
+
+    let results: Vec<_> = (0..9)
+        .map(|_| receiver.recv_timeout(Duration::from_secs(10)).unwrap())
+        .collect();
+    for ids in results.iter().filter(|ids| !ids.is_empty()) {
+        assert!(keys
+            .iter()
+            .all(|key| Some(ids[key]) == asset_manager.peek(*key).map(|handle| handle.id())));
+    }
+    let promotions = promotions.lock().unwrap();
+    assert_eq!(promotions.len(), keys.len());
+    assert!(promotions.values().all(|count| *count == 1));
+}
+
+#[test]
+fn loader_settings_apply_to_loads_with_settings() {
+    let mut app = app();
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    asset_manager.insert_loaded_with_settings(Key::A, "a.txt", |settings: &mut TextSettings| {
+        settings.uppercase = true;
+    });
+    asset_manager.insert_loaded(Key::B, "b.txt");
+    app.insert_resource(asset_manager);
+    run_until(&mut app, |app| manager(app).state_counts().loaded == 2);
+
+    let upper = manager(&app).get(Key::A).unwrap();
+    let lower = manager(&app).get(Key::B).unwrap();
+    assert_eq!(content(&app, &upper).as_deref(), Some("A"));
+    assert_eq!(content(&app, &lower).as_deref(), Some("b"));
+}
+
+#[test]
+fn strict_managers_log_gets_of_missing_keys() {
+    let app = app();
+    let strict = AssetManager::<Key, Text>::new_strict(asset_server(&app));
+    let lenient = AssetManager::<Key, Text>::new(asset_server(&app));
+    strict.insert(Key::A, "a.txt");
+    lenient.insert(Key::A, "a.txt");
+
+    let log = ErrorLog::default();
+    tracing::subscriber::with_default(log.clone(), || {
+        assert!(strict.get(Key::A).is_some());
+        assert!(strict.get(Key::B).is_none());
+        assert!(lenient.get(Key::B).is_none());
+    });
+
+    assert_eq!(
+        *log.0.lock().unwrap(),
+        vec!["no asset is registered under the key B".to_owned()]
+    );
+}
+
+#[test]
+fn labeled_assets_are_addressed_by_label() {
+    let app = app();
+    let asset_manager = AssetManager::<u32, Text>::new(asset_server(&app));
+
+    assert_eq!(
+        asset_manager.insert_labeled(1, "model.gltf", "Mesh0"),
+        Ok(())
+    );
+    assert_eq!(
+        asset_manager.insert_labeled(2, "model.gltf#Mesh0", "Mesh1"),
+        Ok(())
+    );
+    assert_eq!(
+        asset_manager.insert_labeled(3, "model.gltf", ""),
+        Err(AssetManagerError::EmptyLabel)
+    );
+    assert_eq!(
+        asset_manager.path_of(1).as_deref(),
+        Some("model.gltf#Mesh0")
+    );
+    assert_eq!(
+        asset_manager.path_of(2).as_deref(),
+        Some("model.gltf#Mesh1")
+    );
+    assert!(!asset_manager.contains_key(3));
+}
+
+#[test]
+fn managers_are_built_from_vecs_and_hash_maps_of_pairs() {
+    let app = app();
+    let pairs = vec![(Key::A, "a.txt".to_owned()), (Key::B, "b.txt".to_owned())];
+    let lazy = AssetManager::<Key, Text>::from_pairs(asset_server(&app), pairs.clone());
+    let loaded = AssetManager::<Key, Text>::from_pairs_loaded(
+        asset_server(&app),
+        pairs.into_iter().collect::<HashMap<_, _>>(),
+    );
+
+    let mut lazy_entries = lazy.entries();
+    lazy_entries.sort_by_key(|(key, _)| *key);
+    assert_eq!(
+        lazy_entries,
+        vec![(Key::A, LoadStyle::Lazy), (Key::B, LoadStyle::Lazy)]
+    );
+    let mut loaded_entries = loaded.entries();
+    loaded_entries.sort_by_key(|(key, _)| *key);
+    assert_eq!(
+        loaded_entries,
+        vec![(Key::A, LoadStyle::Loaded), (Key::B, LoadStyle::Loaded)]
+    );
+    assert_eq!(loaded.path_of(Key::B).as_deref(), Some("b.txt"));
+}
+
+#[test]
+fn chained_with_and_with_loaded_register_every_entry() {
+    let app = app();
+    let asset_manager = AssetManager::<u32, Text>::new(asset_server(&app))
+        .with(&[(1, "a.txt"), (2, "b.txt")])
+        .with_loaded(&[(3, "c.txt")]);
+
+    let mut entries = asset_manager.entries();
+    entries.sort_by_key(|(key, _)| *key);
+    assert_eq!(
+        entries,
+        vec![
+            (1, LoadStyle::Lazy),
+            (2, LoadStyle::Lazy),
+            (3, LoadStyle::Loaded)
+        ]
+    );
+    assert!(asset_manager.peek(1).is_none());
+    assert!(asset_manager.peek(3).is_some());
+    assert_eq!(asset_manager.path_of(2).as_deref(), Some("b.txt"));
+}
+
+#[test]
+fn load_all_promotes_every_lazy_asset() {
+    let mut app = app();
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    asset_manager.insert_loaded(Key::A, "a.txt");
+    asset_manager.insert(Key::B, "b.txt");
+    let loaded = asset_manager.peek(Key::A).unwrap();
+
+    asset_manager.load_all();
+    assert!(asset_manager
+        .entries()
+        .iter()
+        .all(|(_, style)| *style == LoadStyle::Loaded));
+    assert_eq!(asset_manager.peek(Key::A).unwrap().id(), loaded.id());
+    app.insert_resource(asset_manager);
+    run_until(&mut app, |app| manager(app).state_counts().loaded == 2);
+}
+
+#[test]
+fn progress_counts_only_requested_assets() {
+    let mut app = app();
+    let asset_manager = AssetManager::<u32, Text>::new(asset_server(&app));
+    assert_eq!(asset_manager.progress_fraction(), 0.0);
+    asset_manager.insert_many_loaded(&[(1, "a.txt"), (2, "b.txt")]);
+    asset_manager.insert(3, "c.txt");
+    assert_eq!(asset_manager.progress(), (0, 2));
+    app.insert_resource(asset_manager);
+
+    run_until(&mut app, |app| numbered_manager(app).progress() == (2, 2));
+    assert_eq!(numbered_manager(&app).progress_fraction(), 1.0);
+}
+
+#[test]
+fn failed_loads_are_reported_and_retried() {
+    let mut app = app();
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    asset_manager.insert_loaded(Key::A, "a.txt");
+    asset_manager.insert_loaded(Key::B, "missing.txt");
+    app.insert_resource(asset_manager);
+    run_until(&mut app, |app| {
+        manager(app).is_loaded(Key::A) && !manager(app).failed_keys().is_empty()
+    });
+    assert_eq!(manager(&app).failed_keys(), vec![Key::B]);
+
+    assert_eq!(manager(&app).retry_failed(), vec![Key::B]);
+    run_until(&mut app, |app| {
+        manager(app).load_state(Key::B) == Some(LoadState::Failed)
+    });
+    assert_eq!(manager(&app).failed_keys(), vec![Key::B]);
+}
+
+#[test]
+fn fallback_is_returned_until_the_asset_loads() {
+    let mut app = app();
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    asset_manager.insert(Key::A, "a.txt");
+    let loading = asset_manager.get_or_fallback(Key::A);
+    assert_eq!(
+        Some(loading.id()),
+        asset_manager.peek(Key::A).map(|handle| handle.id())
+    );
+
+    let fallback = app.world.resource_mut::<Assets<Text>>().add(Text {
+        content: "placeholder".to_owned(),
+        dependency: None,
+    });
+    asset_manager.set_fallback(fallback.clone());
+    assert_eq!(asset_manager.get_or_fallback(Key::A).id(), fallback.id());
+    assert_eq!(asset_manager.get_or_fallback(Key::B).id(), fallback.id());
+    app.insert_resource(asset_manager);
+
+    run_until(&mut app, |app| manager(app).is_loaded(Key::A));
+    assert_eq!(manager(&app).get_or_fallback(Key::A).id(), loading.id());
+}
+
+#[test]
+fn renamed_keys_keep_their_handles() {
+    let app = app();
+    let asset_manager = AssetManager::<u32, Text>::new(asset_server(&app));
+    asset_manager.insert_loaded(1, "a.txt");
+    asset_manager.insert(2, "b.txt");
+    asset_manager.add_tag(1, "hud");
+    let handle = asset_manager.peek(1).unwrap();
+
+    assert!(asset_manager.rename_key(1, 2));
+    assert_eq!(asset_manager.get(2).unwrap().id(), handle.id());
+    assert!(asset_manager.get(1).is_none());
+    assert_eq!(asset_manager.path_of(2).as_deref(), Some("a.txt"));
+    assert_eq!(asset_manager.keys_with_tag("hud"), vec![2]);
+    assert!(!asset_manager.rename_key(1, 3));
+    assert_eq!(asset_manager.len(), 1);
+}
+
+#[test]
+fn iterator_inserts_and_gets_accept_any_iterable() {
+    let app = app();
+    let asset_manager = AssetManager::<u32, Text>::new(asset_server(&app));
+    asset_manager.insert_many_iter(vec![(1, "a.txt"), (2, "b.txt")]);
+    let paths = ["c.txt"];
+    asset_manager.insert_many_iter(paths.iter().map(|path| (3, *path)));
+    assert_eq!(asset_manager.len(), 3);
+    assert_eq!(asset_manager.path_of(3).as_deref(), Some("c.txt"));
+
+    let handles = asset_manager.get_many_iter((0..5).map(|key| key + 1));
+    assert_eq!(handles.len(), 3);
+    assert_eq!(asset_manager.get_many_iter(vec![3, 9]).len(), 1);
+}
+
+#[test]
+fn batch_inserts_follow_each_entrys_style() {
+    let app = app();
+    let asset_manager = AssetManager::<u32, Text>::new(asset_server(&app));
+    asset_manager.insert_many_with_styles(&[
+        (1, "a.txt", LoadStyle::Lazy),
+        (2, "b.txt", LoadStyle::Loaded),
+        (3, "c.txt", LoadStyle::Preload),
+    ]);
+
+    assert!(asset_manager.peek(1).is_none());
+    assert!(asset_manager.peek(2).is_some());
+    assert!(asset_manager.peek(3).is_none());
+    assert_eq!(asset_manager.style_breakdown(), (1, 1, 1));
+
+    asset_manager.load_preload();
+    assert!(asset_manager.peek(1).is_none());
+    assert!(asset_manager.peek(3).is_some());
+}
+
+#[test]
+fn wait_for_resolves_once_the_asset_loads_or_fails() {
+    let mut app = app();
+    let asset_manager = AssetManager::<u32, Text>::new(asset_server(&app));
+    asset_manager.insert(1, "a.txt");
+    asset_manager.insert(2, "missing.txt");
+
+    let mut results = Vec::new();
+    for key in [1, 2, 3] {
+        let mut waiting = Box::pin(asset_manager.wait_for(key));
+        run_until(&mut app, |_| match block_on(poll_once(&mut waiting)) {
+            Some(result) => {
+                results.push(result);
+                true
+            }
+            None => false,
+        });
+    }
+
+    let handle = results[0].as_ref().unwrap();
+    assert_eq!(
+        Some(handle.id()),
+        asset_manager.peek(1).map(|handle| handle.id())
+    );
+    assert_eq!(results[1], Err(AssetManagerError::LoadFailed));
+    assert_eq!(results[2], Err(AssetManagerError::MissingKey));
+}
+
+#[test]
+fn groups_load_and_unload_together() {
+    let app = app();
+    let asset_manager = AssetManager::<u32, Text>::new(asset_server(&app));
+    asset_manager.insert_in_group(1, "a.txt", "menu");
+    asset_manager.insert_in_group(2, "b.txt", "gameplay");
+    asset_manager.insert_in_group(3, "c.txt", "menu");
+    asset_manager.add_tag(3, "gameplay");
+    let mut menu = asset_manager.keys_in_group("menu");
+    menu.sort();
+    assert_eq!(menu, vec![1, 3]);
+
+    asset_manager.load_group("menu");
+    assert!(asset_manager.peek(1).is_some());
+    assert!(asset_manager.peek(2).is_none());
+    assert!(asset_manager.peek(3).is_some());
+
+    // Unloading one group unloads assets shared with another group too
+    asset_manager.load_group("gameplay");
+    asset_manager.unload_group("menu");
+    assert!(asset_manager.peek(1).is_none());
+    assert!(asset_manager.peek(2).is_some());
+    assert!(asset_manager.peek(3).is_none());
+    assert_eq!(asset_manager.len(), 3);
+}
+
+#[test]
+fn remove_if_removes_only_matching_paths() {
+    let app = app();
+    let asset_manager = AssetManager::<u32, Text>::new(asset_server(&app));
+    asset_manager.insert_many(&[
+        (1, "a.tmp"),
+        (2, "a.txt"),
+        (3, "levels/b.tmp"),
+        (4, "b.txt"),
+    ]);
+
+    assert_eq!(asset_manager.remove_if(|_, path| path.ends_with(".tmp")), 2);
+    let mut keys = asset_manager.keys();
+    keys.sort();
+    assert_eq!(keys, vec![2, 4]);
+    assert_eq!(asset_manager.path_of(2).as_deref(), Some("a.txt"));
+    assert_eq!(asset_manager.remove_if(|_, path| path.ends_with(".tmp")), 0);
+}
+
+#[test]
+fn split_off_tag_moves_tagged_assets_with_their_state() {
+    let app = app();
+    let asset_manager = AssetManager::<u32, Text>::new(asset_server(&app));
+    asset_manager.insert_loaded(1, "a.txt");
+    asset_manager.insert_preload(2, "b.txt");
+    asset_manager.insert(3, "c.txt");
+    asset_manager.add_tag(1, "level");
+    asset_manager.add_tag(2, "level");
+    asset_manager.add_tag(2, "music");
+    asset_manager.add_tag(3, "menu");
+    let handle = asset_manager.peek(1).unwrap();
+
+    let level = asset_manager.split_off_tag("level");
+
+    assert_eq!(asset_manager.keys(), vec![3]);
+    assert_eq!(asset_manager.keys_with_tag("menu"), vec![3]);
+    assert!(asset_manager.keys_with_tag("level").is_empty());
+    assert!(asset_manager.keys_with_tag("music").is_empty());
+
+    assert_eq!(asset_manager.style_breakdown(), (1, 0, 0));
+    assert_eq!(level.style_breakdown(), (0, 1, 1));
+    assert_eq!(level.peek(1).unwrap().id(), handle.id());
+    assert!(level.peek(2).is_none());
+    let mut tagged = level.keys_with_tag("level");
+    tagged.sort();
+    assert_eq!(tagged, vec![1, 2]);
+    assert_eq!(level.keys_with_tag("music"), vec![2]);
+}
+
+#[test]
+fn from_key_paths_registers_each_key_at_its_own_path() {
+    let app = app();
+    let asset_manager =
+        AssetManager::<Key, Text>::from_key_paths(asset_server(&app), [Key::A, Key::B]);
+
+    assert_eq!(asset_manager.len(), 2);
+    assert_eq!(asset_manager.path_of(Key::A).as_deref(), Some("a.txt"));
+    assert_eq!(asset_manager.path_of(Key::B).as_deref(), Some("b.txt"));
+    assert_eq!(asset_manager.style_breakdown(), (2, 0, 0));
+    assert!(asset_manager.peek(Key::A).is_none());
+}
+
+#[test]
+fn with_entry_inserts_vacant_keys_and_modifies_occupied_ones() {
+    let app = app();
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    let upsert = |path: &str| {
+        asset_manager.with_entry(Key::A, |entry| match entry {
+            Entry::Occupied(mut occupied) => {
+                *occupied.get_mut() = AssetHandle::Lazy(path.to_owned());
+                false
+            }
+            Entry::Vacant(vacant) => {
+                vacant.insert(AssetHandle::Lazy(path.to_owned()));
+                true
+            }
+        })
+    };
+
+    assert!(upsert("a.txt"));
+    assert_eq!(asset_manager.path_of(Key::A).as_deref(), Some("a.txt"));
+    assert!(!upsert("b.txt"));
+    assert_eq!(asset_manager.path_of(Key::A).as_deref(), Some("b.txt"));
+    assert_eq!(asset_manager.len(), 1);
+}
+
+#[test]
+fn preload_tracked_progress_climbs_to_one() {
+    let mut app = app();
+    let asset_manager = AssetManager::<u32, Text>::new(asset_server(&app));
+    asset_manager.insert_many(&[(1, "a.txt"), (2, "dependent.txt")]);
+
+    let (handles, progress) = asset_manager.preload_tracked(&[1, 2]);
+    assert_eq!(handles.len(), 2);
+    assert!(handles.iter().all(Handle::is_strong));
+    assert!(progress() < 1.0);
+
+    let mut fractions = Vec::new();
+    run_until(&mut app, |_| {
+        fractions.push(progress());
+        progress() == 1.0
+    });
+    assert!(fractions.windows(2).all(|pair| pair[0] <= pair[1]));
+}
+
+#[test]
+fn with_capacity_for_fits_every_key_without_reallocating() {
+    let app = app();
+    let asset_manager = AssetManager::<Key, Text>::with_capacity_for(asset_server(&app));
+    let capacity = asset_manager.capacity();
+    assert!(capacity >= Key::COUNT);
+
+    asset_manager.insert_many(&[(Key::A, "a.txt"), (Key::B, "b.txt")]);
+    assert_eq!(asset_manager.capacity(), capacity);
+}
+
+#[test]
+fn wait_all_resolves_once_every_asset_loads_or_one_fails() {
+    let mut app = app();
+    let asset_manager = AssetManager::<u32, Text>::new(asset_server(&app));
+    asset_manager.insert_in_group(1, "a.txt", "level");
+    asset_manager.insert_in_group(2, "dependent.txt", "level");
+    asset_manager.insert(3, "missing.txt");
+
+    let mut results = Vec::new();
+    let mut level = asset_manager.keys_in_group("level");
+    level.sort();
+    for keys in [level, vec![1, 3]] {
+        let mut waiting = Box::pin(asset_manager.wait_all(&keys));
+        run_until(&mut app, |_| match block_on(poll_once(&mut waiting)) {
+            Some(result) => {
+                results.push(result);
+                true
+            }
+            None => false,
+        });
+    }
+
+    let handles = results[0].as_ref().unwrap();
+    assert_eq!(handles.len(), 2);
+    assert!(asset_manager.is_loaded(1));
+    assert!(asset_manager.is_loaded(2));
+    assert!(handles.iter().all(|handle| {
+        asset_manager
+            .asset_server
+            .recursive_dependency_load_state(handle.id())
+            == RecursiveDependencyLoadState::Loaded
+    }));
+    assert_eq!(results[1], Err(vec![3]));
+}
+
+#[test]
+fn get_from_loads_from_another_source_with_a_distinct_handle() {
+    let mut app = app_with_source("alt", PathBuf::from("assets/alt"));
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    asset_manager.insert_loaded(Key::A, "a.txt");
+    let default = asset_manager.get(Key::A).unwrap();
+    let alt = asset_manager
+        .get_from(Key::A, AssetSourceId::from("alt"))
+        .unwrap();
+    assert!(alt.is_strong());
+    assert_ne!(alt.id(), default.id());
+    assert!(asset_manager
+        .get_from(Key::B, AssetSourceId::from("alt"))
+        .is_none());
+    app.insert_resource(asset_manager);
+
+    run_until(&mut app, |app| {
+        content(app, &default).is_some() && content(app, &alt).is_some()
+    });
+    assert_eq!(content(&app, &default).as_deref(), Some("a"));
+    assert_eq!(content(&app, &alt).as_deref(), Some("alt a"));
+    assert_eq!(manager(&app).get(Key::A).unwrap().id(), default.id());
+}
+
+#[test]
+fn shed_reverts_the_least_recently_accessed_assets() {
+    let app = app();
+    let asset_manager = AssetManager::<u32, Text>::new(asset_server(&app));
+    let time = Arc::new(Mutex::new(Instant::now()));
+    let clock = time.clone();
+    asset_manager.set_clock(Box::new(move || *clock.lock().unwrap()));
+    (1..=6).for_each(|key| asset_manager.insert_loaded(key, "a.txt"));
+
+    // Key 6 is never accessed, so it's shed first
+    for key in [3, 1, 5, 2, 4] {
+        *time.lock().unwrap() += Duration::from_secs(1);
+        asset_manager.get(key);
+    }
+    asset_manager.shed(2);
+
+    let loaded: Vec<u32> = (1..=6)
+        .filter(|key| asset_manager.peek(*key).is_some())
+        .collect();
+    assert_eq!(loaded, vec![2, 4]);
+    assert_eq!(asset_manager.len(), 6);
+    assert_eq!(asset_manager.path_of(3).as_deref(), Some("a.txt"));
+
+    asset_manager.shed(2);
+    assert!(asset_manager.peek(2).is_some() && asset_manager.peek(4).is_some());
+}
+
+#[test]
+fn promoted_assets_keep_their_original_path() {
+    let app = app();
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    asset_manager.insert(Key::A, "./a.txt");
+    asset_manager.insert_loaded(Key::B, "b.txt");
+
+    asset_manager.get(Key::A).unwrap();
+    assert!(asset_manager.peek(Key::A).is_some());
+    assert_eq!(asset_manager.path_of(Key::A).as_deref(), Some("./a.txt"));
+    assert_eq!(asset_manager.path_of(Key::B).as_deref(), Some("b.txt"));
+}
+
+#[test]
+fn get_cached_returns_the_stored_handle_without_loading() {
+    let app = app();
+    let asset_manager = AssetManager::<Key, Text>::new(asset_server(&app));
+    asset_manager.insert(Key::A, "a.txt");
+
+    assert!(asset_manager.get_cached(Key::A).is_none());
+    assert!(asset_manager.peek(Key::A).is_none());
+    assert!(asset_manager.get_cached(Key::B).is_none());
+
+    asset_manager.load(Key::A);
+    let stored = asset_manager.peek(Key::A).unwrap();
+    let first = asset_manager.get_cached(Key::A).unwrap();
+    let second = asset_manager.get_cached(Key::A).unwrap();
+    assert_eq!(first.id(), stored.id());
+    assert_eq!(second.id(), stored.id());
+    assert!(!second.is_strong());
+}
@@ -0,0 +1,230 @@
+use crate::compose_source_path;
+use bevy::{
+    asset::{io::AssetSourceId, LoadState, LoadedUntypedAsset, UntypedHandle},
+    prelude::{AssetServer, Handle, Resource},
+    utils::hashbrown::HashMap,
+};
+use std::{any::TypeId, hash::Hash, sync::RwLock};
+
+/// Resource managing assets of different types under a single set of keys.
+///
+/// Assets are loaded with `AssetServer::load_untyped`, so their types are only known once they have finished loading.
+#[derive(Resource)]
+pub struct UntypedAssetManager<Key>
+where
+    Key: PartialEq + Eq + Hash,
+{
+    assets: RwLock<HashMap<Key, UntypedEntry>>,
+    asset_server: AssetServer,
+    source: RwLock<AssetSourceId<'static>>,
+}
+
+impl<Key> UntypedAssetManager<Key>
+where
+    Key: PartialEq + Eq + Hash + Clone,
+{
+    /// Creates a new `UntypedAssetManager` instance.
+    pub fn new(asset_server: AssetServer) -> Self {
+        Self {
+            assets: RwLock::new(HashMap::new()),
+            asset_server,
+            source: RwLock::new(AssetSourceId::Default),
+        }
+    }
+
+    /// Creates a copy of the manager bound to another asset server, with every asset lazy again.
+    ///
+    /// The asset source is copied along with the assets.
+    pub fn with_asset_server(&self, asset_server: AssetServer) -> Self {
+        let untyped = Self::new(asset_server);
+        *untyped.source.write().unwrap() = self.source();
+        *untyped.assets.write().unwrap() = self
+            .assets
+            .read()
+            .unwrap()
+            .iter()
+            .map(|(key, entry)| (key.clone(), UntypedEntry::Lazy(entry.path().to_owned())))
+            .collect();
+
+        untyped
+    }
+
+    /// Sets the asset source which future loads are read from.
+    ///
+    /// Paths which name their own source, like `"embedded://icon.png"`, are unaffected.
+    pub fn set_source(&self, source: AssetSourceId<'static>) {
+        *self.source.write().unwrap() = source;
+    }
+
+    /// Gets the asset source which loads are read from.
+    pub fn source(&self) -> AssetSourceId<'static> {
+        self.source.read().unwrap().clone()
+    }
+
+    /// Inserts an asset into the manager, which will be loaded when it's first requested.
+    pub fn insert(&self, key: Key, path: &str) {
+        self.assets
+            .write()
+            .unwrap()
+            .insert(key, UntypedEntry::Lazy(path.to_owned()));
+    }
+
+    /// Inserts an asset into the manager and starts loading it.
+    pub fn insert_loaded(&self, key: Key, path: &str) {
+        let mut entry = UntypedEntry::Lazy(path.to_owned());
+        entry.load(&self.asset_server, &self.source());
+
+        self.assets.write().unwrap().insert(key, entry);
+    }
+
+    /// Loads an asset if it was added lazily, doing nothing if it is already loaded.
+    pub fn load(&self, key: Key) {
+        if let Some(entry) = self.assets.write().unwrap().get_mut(&key) {
+            entry.load(&self.asset_server, &self.source());
+        }
+    }
+
+    /// Gets an untyped handle to an asset, ensuring it's loaded if it was added lazily.
+    ///
+    /// Returns `None` until the asset server has finished loading the asset and determined its type.
+    pub fn get_untyped(&self, key: Key) -> Option<UntypedHandle> {
+        let mut lock = self.assets.write().unwrap();
+        let entry = lock.get_mut(&key)?;
+        entry.load(&self.asset_server, &self.source());
+
+        entry.resolve(&self.asset_server)
+    }
+
+    /// Gets a typed handle to an asset, ensuring it's loaded if it was added lazily.
+    ///
+    /// Returns `None` if the asset hasn't finished loading or isn't an `A`.
+    pub fn get_typed<A>(&self, key: Key) -> Option<Handle<A>>
+    where
+        A: bevy::asset::Asset,
+    {
+        self.get_untyped(key)
+            .filter(|handle| handle.type_id() == TypeId::of::<A>())
+            .map(UntypedHandle::typed::<A>)
+    }
+
+    /// Gets the path an asset was registered with.
+    pub fn path_of(&self, key: Key) -> Option<String> {
+        self.assets
+            .read()
+            .unwrap()
+            .get(&key)
+            .map(|entry| entry.path().to_owned())
+    }
+
+    /// Checks if an asset is registered under a key.
+    pub fn contains_key(&self, key: Key) -> bool {
+        self.assets.read().unwrap().contains_key(&key)
+    }
+
+    /// Gets the number of assets registered with the manager.
+    pub fn len(&self) -> usize {
+        self.assets.read().unwrap().len()
+    }
+
+    /// Checks if the manager has no assets registered.
+    pub fn is_empty(&self) -> bool {
+        self.assets.read().unwrap().is_empty()
+    }
+
+    /// Removes an asset from the manager, returning whether it was registered.
+    pub fn remove(&self, key: Key) -> bool {
+        self.assets.write().unwrap().remove(&key).is_some()
+    }
+
+    /// Removes every asset whose key and path match `pred`, returning how many were removed.
+    pub fn remove_if(&self, pred: impl Fn(&Key, &str) -> bool) -> usize {
+        self.assets
+            .write()
+            .unwrap()
+            .extract_if(|key, entry| pred(key, entry.path()))
+            .count()
+    }
+
+    /// Moves an asset to another key, overwriting any asset already registered under `to`.
+    ///
+    /// Returns `false` if no asset is registered under `from`.
+    pub fn rename_key(&self, from: Key, to: Key) -> bool {
+        let mut lock = self.assets.write().unwrap();
+        let Some(entry) = lock.remove(&from) else {
+            return false;
+        };
+        lock.insert(to, entry);
+
+        true
+    }
+
+    /// Removes the assets registered under `keys`, returning them in a new manager bound to the same asset server and source.
+    pub fn split_off(&self, keys: &[Key]) -> Self {
+        let split = Self::new(self.asset_server.clone());
+        *split.source.write().unwrap() = self.source();
+
+        let mut lock = self.assets.write().unwrap();
+        let mut split_lock = split.assets.write().unwrap();
+        keys.iter().for_each(|key| {
+            if let Some(entry) = lock.remove(key) {
+                split_lock.insert(key.clone(), entry);
+            }
+        });
+        drop(split_lock);
+
+        split
+    }
+
+    /// Removes every asset from the manager.
+    pub fn clear(&self) {
+        self.assets.write().unwrap().clear();
+    }
+}
+
+/// Enum representing different states of an asset handle whose type is only known once loaded.
+enum UntypedEntry {
+    /// Represents a lazy untyped asset with the path.
+    Lazy(String),
+    /// Represents an untyped asset which has started loading, with its path and the path it was loaded from.
+    Loading {
+        path: String,
+        source_path: String,
+        handle: Handle<LoadedUntypedAsset>,
+    },
+}
+
+impl UntypedEntry {
+    /// Starts loading the asset from `source` if it's lazy.
+    fn load(&mut self, asset_server: &AssetServer, source: &AssetSourceId) {
+        if let UntypedEntry::Lazy(path) = self {
+            let source_path = compose_source_path(source, path);
+            *self = UntypedEntry::Loading {
+                handle: asset_server.load_untyped(source_path.clone()),
+                path: std::mem::take(path),
+                source_path,
+            };
+        }
+    }
+
+    /// Gets an untyped handle to the asset once the asset server has finished loading it and determined its type.
+    fn resolve(&self, asset_server: &AssetServer) -> Option<UntypedHandle> {
+        match self {
+            UntypedEntry::Loading {
+                source_path,
+                handle,
+                ..
+            } => match asset_server.get_load_state(handle.id()) {
+                Some(LoadState::Loaded) => asset_server.get_handle_untyped(source_path.to_owned()),
+                _ => None,
+            },
+            UntypedEntry::Lazy(_) => None,
+        }
+    }
+
+    /// Gets the path the asset was inserted with.
+    fn path(&self) -> &str {
+        match self {
+            UntypedEntry::Lazy(path) | UntypedEntry::Loading { path, .. } => path,
+        }
+    }
+}
@@ -0,0 +1,9 @@
+#![cfg(feature = "derive")]
+
+#[test]
+fn derive_diagnostics() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/duplicate_key.rs");
+    cases.compile_fail("tests/ui/duplicate_path.rs");
+    cases.pass("tests/ui/aliased_paths.rs");
+}
@@ -0,0 +1,13 @@
+use bevy_asset_manager::{AssetKey, AssetPath};
+
+#[derive(AssetKey, Clone, PartialEq, Eq, Hash)]
+enum Sound {
+    #[asset(path = "sounds/engine.ogg")]
+    EngineOn,
+    #[asset(path = "sounds/engine.ogg")]
+    EngineOff,
+}
+
+fn main() {
+    assert_eq!(Sound::EngineOn.asset_path(), Sound::EngineOff.asset_path());
+}
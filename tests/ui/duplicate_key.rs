@@ -0,0 +1,11 @@
+use bevy_asset_manager::AssetKey;
+
+#[derive(AssetKey)]
+enum Sound {
+    #[asset(path = "sounds/engine-on.ogg")]
+    Engine,
+    #[asset(path = "sounds/engine-off.ogg")]
+    Engine,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#![deny(deprecated)]
+
+use bevy_asset_manager::AssetKey;
+
+#[derive(AssetKey, Clone, PartialEq, Eq, Hash)]
+enum Sound {
+    #[asset(path = "sounds/engine.ogg")]
+    EngineOn,
+    #[asset(path = "sounds/engine.ogg")]
+    EngineOff,
+}
+
+fn main() {}